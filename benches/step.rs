@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Times [`PetriDish::step`] against [`PetriDish::step_scalar`] on the same
+//! random boards.
+//!
+//! Run with `cargo bench`.
+
+use core::hint::black_box;
+use core::iter;
+use core::time::Duration;
+use std::time::Instant;
+
+use dandelifeon::simulation::{PetriDish, Status};
+use flate2 as _;
+use rand::rngs::SmallRng;
+use rand::{Rng as _, SeedableRng as _};
+
+/// How many boards to step with each stepper.
+const BOARDS: usize = 100_000;
+
+/// Steps every board in `dishes` once with `step`, returning how long it took.
+fn time(
+    dishes: &[PetriDish],
+    step: fn(PetriDish) -> (PetriDish, Status),
+) -> Duration {
+    let start: Instant = Instant::now();
+    for dish in dishes {
+        let _: (PetriDish, Status) = black_box(step(black_box(*dish)));
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let mut rng: SmallRng = SmallRng::seed_from_u64(0);
+    let dishes: Vec<PetriDish> =
+        iter::repeat_with(|| rng.random()).take(BOARDS).collect();
+
+    // Warm up the caches and branch predictors before timing anything.
+    let _: Duration = time(&dishes, PetriDish::step);
+    let _: Duration = time(&dishes, PetriDish::step_scalar);
+
+    let fast: Duration = time(&dishes, PetriDish::step);
+    let scalar: Duration = time(&dishes, PetriDish::step_scalar);
+    println!(
+        "step:        {:>9.3} ms for {BOARDS} boards",
+        fast.as_secs_f64() * 1000.0
+    );
+    println!(
+        "step_scalar: {:>9.3} ms for {BOARDS} boards",
+        scalar.as_secs_f64() * 1000.0
+    );
+    println!(
+        "step is {:.1}x faster",
+        scalar.as_secs_f64() / fast.as_secs_f64()
+    );
+}
@@ -0,0 +1,359 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A binary that makes use of the library to search for solutions to the
+//! Dandelifeon.
+
+use core::cmp::Reverse;
+use core::error::Error;
+use core::num::NonZeroUsize;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::{env, fs, io, thread};
+
+use dandelifeon::Hive;
+use dandelifeon::bees::{
+    BeesConfig, Cancelled, Checkpoint, Colony as _, Event, MaxEvaluations,
+    MaxIterations, Never, NoImprovement, Observer, Or, StdoutObserver,
+    TimeBudget,
+};
+use dandelifeon::pareto::{Objectives, ParetoFront};
+use dandelifeon::simulation::{ParseDishError, PetriDish, Rle, RleError};
+use rand::SeedableRng as _;
+use rand::rngs::SmallRng;
+
+/// The command line options, all but one of which take a value.
+const USAGE: &str = "\
+Options:
+    --resume <PATH>             continue the search saved at PATH, keeping its
+                                parameters
+    --checkpoint <PATH>         where to save the search
+                                [default: dandelifeon.checkpoint]
+    --checkpoint-every <N>      save the search every N cycles [default: 100]
+    --start <PATH>              start from the board in PATH, as text or RLE;
+                                can be given more than once
+    --pareto                    also keep every board that no other beats on
+                                mana, cells, blocks and steps all at once, and
+                                print them at the end
+    --scouts <N>
+    --elite-sites <N>
+    --best-sites <N>
+    --elite-recruits <N>
+    --best-recruits <N>
+    --flower-patch-size <N>
+    --stagnation-limit <N>
+    --max-iterations <N>
+    --max-evaluations <N>
+    --time-limit <SECONDS>
+    --patience <N>";
+
+/// The [`Hive`] run by the binary.
+type Search = Hive<Or<Limits, Cancelled<'static>>>;
+
+/// Raised on Ctrl-C or SIGTERM, after which the search stops at the end of the
+/// current cycle.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// The [`StoppingCondition`]s that can be set from the command line, any of
+/// which stops the search. Those that are not set never stop it.
+type Limits = Or<
+    Or<Or<Option<MaxIterations>, Option<MaxEvaluations>>, Option<TimeBudget>>,
+    Option<NoImprovement>,
+>;
+
+/// Everything that can be set from the command line.
+struct Options {
+    /// The parameters of a new search.
+    config: BeesConfig,
+    /// When to stop searching.
+    limits: Limits,
+    /// A saved search to continue instead of starting a new one.
+    resume: Option<PathBuf>,
+    /// Boards to start a new search from.
+    starts: Vec<PathBuf>,
+    /// How often, and where, to save the search.
+    saver: Saver,
+    /// Whether to keep and print a [`ParetoFront`].
+    pareto: bool,
+}
+
+/// An [`Observer`] that prints like [`StdoutObserver`], and saves the
+/// [`Checkpoint`] of the search every few cycles and when interrupted.
+struct Saver {
+    /// Where to save the search.
+    path: PathBuf,
+    /// How many cycles to go between saves.
+    every: usize,
+}
+
+impl Saver {
+    /// Writes `checkpoint` to a temporary file next to [`Saver::path`], and
+    /// then moves it into place, so that an interrupted save does not ruin an
+    /// earlier one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either file cannot be written.
+    fn save(&self, checkpoint: &Checkpoint<Search>) -> io::Result<()> {
+        let mut temporary: PathBuf = self.path.clone();
+        temporary.as_mut_os_string().push(".tmp");
+        fs::write(&temporary, checkpoint.to_string())?;
+        fs::rename(&temporary, &self.path)
+    }
+}
+
+impl Observer<Search> for Saver {
+    fn observe(&mut self, event: Event<Search>) {
+        StdoutObserver.observe(event);
+    }
+
+    fn checkpoint(&mut self, checkpoint: &Checkpoint<Search>) {
+        let iterations: usize = checkpoint.progress().iterations();
+        let interrupted: bool = INTERRUPTED.load(Ordering::Relaxed);
+        if !iterations.is_multiple_of(self.every) && !interrupted {
+            return;
+        }
+        match self.save(checkpoint) {
+            Ok(()) if interrupted => {
+                eprintln!(
+                    "Saved cycle {iterations} to {}",
+                    self.path.display()
+                );
+            }
+            Ok(()) => {}
+            Err(error) => {
+                eprintln!(
+                    "warning: could not save to {}: {error}",
+                    self.path.display()
+                );
+            }
+        }
+    }
+}
+
+/// Runs the bees algorithm to search for solutions to the Dandelifeon.
+fn main() -> ExitCode {
+    let options: Options = match options_from_args() {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rng: SmallRng = SmallRng::seed_from_u64(42);
+    let checkpoint: Checkpoint<Search> = match start(&options, &mut rng) {
+        Ok(checkpoint) => checkpoint,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(error) = ctrlc::set_handler(|| {
+        eprintln!("Interrupted, stopping after the current cycle...");
+        INTERRUPTED.store(true, Ordering::Relaxed);
+    }) {
+        eprintln!("error: {error}");
+        return ExitCode::FAILURE;
+    }
+
+    let mut hive: Search =
+        Hive::new(Or::new(options.limits, Cancelled::new(&INTERRUPTED)));
+    let threads: NonZeroUsize =
+        thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+    let mut observer: (Saver, Option<ParetoFront>) =
+        (options.saver, options.pareto.then(ParetoFront::new));
+    let winning =
+        hive.resume_parallel::<SmallRng, _>(checkpoint, threads, &mut observer);
+    let (best, winner) = (winning.fitness(), winning.solution());
+    println!("Finished! Best score was {best}. Solution is:\n{winner}");
+    println!("As RLE:\n{}", winner.rle());
+
+    if let (_, Some(front)) = observer {
+        let mut members: Vec<(PetriDish, Objectives)> = front.into_members();
+        members.sort_by_key(|&(_, objectives): &(PetriDish, Objectives)| {
+            (
+                Reverse(objectives.mana()),
+                objectives.cell_cost(),
+                objectives.block_cost(),
+                objectives.steps(),
+            )
+        });
+        println!("Pareto front of {} boards:", members.len());
+        for (dish, objectives) in members {
+            println!("{objectives}\n{}", dish.rle());
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Gets the [`Checkpoint`] to search from, which is either the saved search
+/// given by `--resume`, or a new search starting from the boards given by
+/// `--start`.
+///
+/// # Errors
+///
+/// Returns an error if both `--resume` and `--start` were given, or if any of
+/// the files cannot be read or parsed.
+fn start(
+    options: &Options,
+    rng: &mut SmallRng,
+) -> Result<Checkpoint<Search>, Box<dyn Error>> {
+    let Some(ref path) = options.resume else {
+        let dishes: Vec<PetriDish> = options
+            .starts
+            .iter()
+            .map(|path: &PathBuf| load_dish(path))
+            .collect::<Result<Vec<PetriDish>, Box<dyn Error>>>()?;
+        return Ok(Checkpoint::with_solutions(&options.config, dishes, rng));
+    };
+    if !options.starts.is_empty() {
+        return Err("--start cannot be used with --resume".into());
+    }
+    let checkpoint: Checkpoint<Search> = read(path)?
+        .parse()
+        .map_err(|error| format!("{}: {error}", path.display()))?;
+    Ok(checkpoint)
+}
+
+/// Reads a single board from `path`, which may hold either of the text
+/// formats [`PetriDish`] parses, or RLE.
+///
+/// # Errors
+///
+/// Returns an error if `path` cannot be read, or holds neither.
+fn load_dish(path: &PathBuf) -> Result<PetriDish, Box<dyn Error>> {
+    let text: String = read(path)?;
+    let as_text: ParseDishError = match text.parse::<PetriDish>() {
+        Ok(dish) => return Ok(dish),
+        Err(error) => error,
+    };
+    let rle: Rle = text.parse().map_err(|as_rle: RleError| {
+        format!(
+            "{}: not a board as text ({as_text}) or as RLE ({as_rle})",
+            path.display()
+        )
+    })?;
+    Ok(rle.dish())
+}
+
+/// Reads the whole of `path`.
+///
+/// # Errors
+///
+/// Returns an error, naming `path`, if it cannot be read.
+fn read(path: &PathBuf) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|error: io::Error| format!("{}: {error}", path.display()))
+}
+
+/// Reads the [`Options`] from the command line. Any parameter that is not
+/// given keeps the value [`Hive`] was built with, and any limit that is not
+/// given is not set.
+///
+/// # Errors
+///
+/// Returns an error if an argument is not one of the options in [`USAGE`],
+/// if a value is missing or is not a number, if `--checkpoint-every` is 0, or
+/// if the parameters do not make a valid [`BeesConfig`].
+fn options_from_args() -> Result<Options, Box<dyn Error>> {
+    let mut scouts: Option<usize> = None;
+    let mut elite_sites: Option<usize> = None;
+    let mut best_sites: Option<usize> = None;
+    let mut elite_recruits: Option<usize> = None;
+    let mut best_recruits: Option<usize> = None;
+    let mut flower_patch_size: Option<usize> = None;
+    let mut stagnation_limit: Option<usize> = None;
+    let mut max_iterations: Option<usize> = None;
+    let mut max_evaluations: Option<usize> = None;
+    let mut time_limit: Option<usize> = None;
+    let mut patience: Option<usize> = None;
+    let mut checkpoint_every: Option<usize> = None;
+    let mut resume: Option<PathBuf> = None;
+    let mut starts: Vec<PathBuf> = Vec::new();
+    let mut checkpoint: Option<PathBuf> = None;
+    let mut pareto: bool = false;
+
+    let mut args = env::args().skip(1);
+    while let Some(option) = args.next() {
+        let mut value =
+            || args.next().ok_or_else(|| format!("{option} needs a value"));
+        let parameter: &mut Option<usize> = match option.as_str() {
+            "--pareto" => {
+                pareto = true;
+                continue;
+            }
+            "--resume" => {
+                resume = Some(PathBuf::from(value()?));
+                continue;
+            }
+            "--checkpoint" => {
+                checkpoint = Some(PathBuf::from(value()?));
+                continue;
+            }
+            "--start" => {
+                starts.push(PathBuf::from(value()?));
+                continue;
+            }
+            "--scouts" => &mut scouts,
+            "--elite-sites" => &mut elite_sites,
+            "--best-sites" => &mut best_sites,
+            "--elite-recruits" => &mut elite_recruits,
+            "--best-recruits" => &mut best_recruits,
+            "--flower-patch-size" => &mut flower_patch_size,
+            "--stagnation-limit" => &mut stagnation_limit,
+            "--max-iterations" => &mut max_iterations,
+            "--max-evaluations" => &mut max_evaluations,
+            "--time-limit" => &mut time_limit,
+            "--patience" => &mut patience,
+            "--checkpoint-every" => &mut checkpoint_every,
+            _ => return Err(format!("unknown option {option}").into()),
+        };
+        *parameter = Some(value()?.parse()?);
+    }
+
+    let default: BeesConfig = Hive::<Never>::CONFIG;
+    let config: BeesConfig = BeesConfig::new(
+        scouts.unwrap_or_else(|| default.scouts()),
+        elite_sites.unwrap_or_else(|| default.elite_sites()),
+        best_sites.unwrap_or_else(|| default.best_sites()),
+        elite_recruits.unwrap_or_else(|| default.elite_recruits()),
+        best_recruits.unwrap_or_else(|| default.best_recruits()),
+        flower_patch_size.unwrap_or_else(|| default.flower_patch_size()),
+        stagnation_limit.unwrap_or_else(|| default.stagnation_limit()),
+    )?;
+    let time_limit: Option<TimeBudget> = time_limit
+        .map(u64::try_from)
+        .transpose()?
+        .map(|seconds: u64| TimeBudget::new(Duration::from_secs(seconds)));
+    let limits: Limits = Or::new(
+        Or::new(
+            Or::new(
+                max_iterations.map(MaxIterations::new),
+                max_evaluations.map(MaxEvaluations::new),
+            ),
+            time_limit,
+        ),
+        patience.map(NoImprovement::new),
+    );
+    let every: usize = checkpoint_every.unwrap_or(100);
+    if every == 0 {
+        return Err("--checkpoint-every must be at least 1".into());
+    }
+    let saver: Saver = Saver {
+        path: checkpoint
+            .unwrap_or_else(|| PathBuf::from("dandelifeon.checkpoint")),
+        every,
+    };
+    Ok(Options {
+        config,
+        limits,
+        resume,
+        starts,
+        saver,
+        pareto,
+    })
+}
@@ -20,22 +20,37 @@
 //!              1 flower_patch[i] = Global_search(flower_patch[i])
 //! ```
 
-use core::array;
 use core::cmp::PartialOrd;
-use core::fmt::{Debug, Display};
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter;
 use core::marker::PhantomData;
+use core::num::NonZeroUsize;
+use core::time::Duration;
+use std::thread::{self, Scope, ScopedJoinHandle};
+use std::time::Instant;
 
-use rand::Rng;
 use rand::distr::{Distribution, StandardUniform};
+use rand::{Rng, SeedableRng};
+
+pub use crate::bees::checkpoint::{
+    ParseCheckpointError, ParseCheckpointErrorKind,
+};
+pub use crate::bees::criteria::{
+    And, Cancelled, MaxEvaluations, MaxIterations, Never, NoImprovement, Or,
+    Progress, StoppingCondition, TargetFitness, TimeBudget,
+};
+pub use crate::bees::events::{Event, Observer, StdoutObserver};
+
+mod checkpoint;
+mod criteria;
+mod events;
 
 /// Implementing [`Colony`] on a struct allows the bees algorithm to be run from
 /// it.
-///
-/// The const generic [usize] `N_SCOUTS` is what is typically referred to as
-/// `ns`, and is the number of scout bees.
-pub trait Colony<const N_SCOUTS: usize>: Debug + Copy
+pub trait Colony: Debug + Copy
 where
-    StandardUniform: Distribution<<Self as Colony<N_SCOUTS>>::Flower>,
+    StandardUniform: Distribution<<Self as Colony>::Flower>,
 {
     /// The [`Colony::Flower`] type represents a possible solution to the
     /// problem being optimized.
@@ -48,23 +63,29 @@ where
     /// according to your needs.
     type Nectar: Debug + Copy + Display + Ord;
 
+    /// Commonly denoted `ns`, this represents the number of scout bees. It
+    /// must be larger than [`Colony::BEST_SITES`], which is checked by
+    /// [`Colony::CONFIG`].
+    const SCOUTS: usize;
+
     /// Commonly denoted `ne`, this represents the number of elite sites. It
-    /// should be smaller than `N_SCOUTS` and [`Colony::BEST_SITES`].
+    /// must be smaller than [`Colony::SCOUTS`] and [`Colony::BEST_SITES`],
+    /// which is checked by [`Colony::CONFIG`].
     const ELITE_SITES: usize;
 
     /// Commonly denoted `nb`, this represents the number of best sites. It
-    /// should be smaller than `N_SCOUTS` and larger than
-    /// [`Colony::ELITE_SITES`].
+    /// must be smaller than [`Colony::SCOUTS`] and larger than
+    /// [`Colony::ELITE_SITES`], which is checked by [`Colony::CONFIG`].
     const BEST_SITES: usize;
 
     /// Commonly denoted `nre`, this represents the number of bees recruited for
-    /// elite sites. It should be equal to or greater than
-    /// [`Colony::BEST_RECRUITS`].
+    /// elite sites. It must be equal to or greater than
+    /// [`Colony::BEST_RECRUITS`], which is checked by [`Colony::CONFIG`].
     const ELITE_RECRUITS: usize;
 
     /// Commonly denoted `nrb`, this represents the number of bees recruited for
-    /// best sites. It should be equal to or less than
-    /// [`Colony::ELITE_RECRUITS`].
+    /// best sites. It must be equal to or less than
+    /// [`Colony::ELITE_RECRUITS`], which is checked by [`Colony::CONFIG`].
     const BEST_RECRUITS: usize;
 
     /// Commonly denoted `a(0)`, this represents the initial size of a flower
@@ -77,129 +98,703 @@ where
     /// for site abandonment.
     const STAGNATION_LIMIT: usize;
 
+    /// The [`BeesConfig`] described by the associated constants of this
+    /// [`Colony`], for use with [`Colony::bees`].
+    ///
+    /// This is checked at compile time, as soon as [`Colony::bees`] or
+    /// [`Colony::bees_parallel`] is used with this [`Colony`], so that
+    /// invalid associated constants are rejected before anything runs, even
+    /// if the search is given some other [`BeesConfig`].
+    ///
+    /// # Example
+    ///
+    /// In these examples, `Ones<NS, NE, NB, NRE, NRB>` is a [`Colony`] with
+    /// [`Colony::SCOUTS`], [`Colony::ELITE_SITES`], [`Colony::BEST_SITES`],
+    /// [`Colony::ELITE_RECRUITS`] and [`Colony::BEST_RECRUITS`] taken from its
+    /// const generics, which default to `8, 1, 4, 3, 2`.
+    ///
+    /// ```rust
+    /// let mut rng: SmallRng = SmallRng::seed_from_u64(15);
+    /// let config: BeesConfig = BeesConfig::new(8, 1, 4, 3, 2, 64, 3)?;
+    /// assert_eq!(Ones::<8, 1, 4, 3, 2>::CONFIG, config);
+    ///
+    /// let _: Scout<Ones<8, 1, 4, 3, 2>> =
+    ///     Ones(1).bees(&config, &mut rng, &mut ());
+    #[cfg_attr(doc, doc = include_str!("bees/ones.md"))]
+    /// There must be more scouts than best sites:
+    ///
+    /// ```compile_fail,E0080
+    /// let mut rng: SmallRng = SmallRng::seed_from_u64(15);
+    /// let config: BeesConfig = BeesConfig::new(8, 1, 4, 3, 2, 64, 3)?;
+    /// let _: Scout<Ones<8, 1, 8, 3, 2>> =
+    ///     Ones(1).bees(&config, &mut rng, &mut ());
+    #[cfg_attr(doc, doc = include_str!("bees/ones.md"))]
+    /// There must be more best sites than elite sites:
+    ///
+    /// ```compile_fail,E0080
+    /// let mut rng: SmallRng = SmallRng::seed_from_u64(15);
+    /// let config: BeesConfig = BeesConfig::new(8, 1, 4, 3, 2, 64, 3)?;
+    /// let _: Scout<Ones<8, 4, 4, 3, 2>> =
+    ///     Ones(1).bees(&config, &mut rng, &mut ());
+    #[cfg_attr(doc, doc = include_str!("bees/ones.md"))]
+    /// Elite sites must recruit at least as many bees as best sites:
+    ///
+    /// ```compile_fail,E0080
+    /// let mut rng: SmallRng = SmallRng::seed_from_u64(15);
+    /// let config: BeesConfig = BeesConfig::new(8, 1, 4, 3, 2, 64, 3)?;
+    /// let _: Scout<Ones<8, 1, 4, 2, 3>> =
+    ///     Ones(1).bees(&config, &mut rng, &mut ());
+    #[cfg_attr(doc, doc = include_str!("bees/ones.md"))]
+    const CONFIG: BeesConfig = match BeesConfig::new(
+        Self::SCOUTS,
+        Self::ELITE_SITES,
+        Self::BEST_SITES,
+        Self::ELITE_RECRUITS,
+        Self::BEST_RECRUITS,
+        Self::FLOWER_PATCH_SIZE,
+        Self::STAGNATION_LIMIT,
+    ) {
+        Ok(config) => config,
+        Err(ConfigError::NotEnoughScouts { .. }) => {
+            panic!("Colony::BEST_SITES must be smaller than Colony::SCOUTS")
+        }
+        Err(ConfigError::TooManyEliteSites { .. }) => {
+            panic!(
+                "Colony::ELITE_SITES must be smaller than Colony::BEST_SITES"
+            )
+        }
+        Err(ConfigError::TooFewEliteRecruits { .. }) => panic!(
+            "Colony::ELITE_RECRUITS must be at least Colony::BEST_RECRUITS"
+        ),
+    };
+
     /// Due to integer math, there is a lower limit on the "radius" seen at
     /// runtime which may be higher than 0. This constant represents that lower
     /// limit, for whatever use it may be.
-    const MINIMUM_RADIUS: usize = {
-        let mut size: usize = Self::FLOWER_PATCH_SIZE;
-        let mut i: usize = 0;
-        while i < Self::STAGNATION_LIMIT {
-            size = size - ((size.div_euclid(10)) * 2);
-            i += 1;
-        }
-        size
-    };
+    const MINIMUM_RADIUS: usize = Self::CONFIG.minimum_radius();
 
     /// Evaluate the fitness of a [`Colony::Flower`].
     fn evaluate(solution: &Self::Flower) -> Self::Nectar;
 
+    /// Evaluate the fitness of many [`Colony::Flower`]s at once, returning
+    /// them in the same order. Override this if evaluating in bulk is faster
+    /// than one at a time. By default, this just calls [`Colony::evaluate`] on
+    /// each [`Colony::Flower`] in turn.
+    fn evaluate_batch(solutions: &[Self::Flower]) -> Vec<Self::Nectar> {
+        solutions.iter().map(Self::evaluate).collect()
+    }
+
     /// Explore random nearby [`Colony::Flower`]s based on the radius size. The
-    /// current best is also provided for consideration.
+    /// current best is also provided for consideration, as is the
+    /// [`BeesConfig`] of the search.
     fn explore<R: Rng>(
         origin: &Self::Flower,
-        current_best: &Scout<N_SCOUTS, Self>,
+        current_best: &Scout<Self>,
         radius: usize,
+        config: &BeesConfig,
         rng: &mut R,
     ) -> Self::Flower;
 
-    /// Return `true` when it is time to stop searching. Use the implementor of
-    /// this trait to manage needed state (such as iteration counts).
-    fn stopping_condition(&mut self) -> bool;
+    /// Return `true` when it is time to stop searching, given the [`Progress`]
+    /// of the search so far. This is checked at the start of every cycle. A
+    /// [`StoppingCondition`] can be held by the implementor of this trait and
+    /// checked here.
+    ///
+    /// Only the [`Progress`] is saved in a [`Checkpoint`], so deciding from it
+    /// alone lets a resumed search stop exactly when it would have.
+    fn stopping_condition(&mut self, progress: &Progress<Self::Nectar>)
+    -> bool;
 
-    /// Assumes `flower_patches` is sorted by fitness. Of the `N_SCOUTS`
-    /// [`Colony::Flower`]s visited, [`Colony::BEST_SITES`] perform the waggle
-    /// dance. Of those scouts, the [`Colony::ELITE_SITES`] very best will
-    /// recruit [`Colony::ELITE_RECRUITS`] foragers, and the remaining
-    /// [`Colony::BEST_SITES`] - [`Colony::ELITE_SITES`] will recruit
-    /// [`Colony::BEST_RECRUITS`].
+    /// Of the [`BeesConfig::scouts`] [`Colony::Flower`]s visited,
+    /// [`BeesConfig::best_sites`] perform the waggle dance. Of those scouts,
+    /// the [`BeesConfig::elite_sites`] very best will recruit
+    /// [`BeesConfig::elite_recruits`] foragers, and the rest will recruit
+    /// [`BeesConfig::best_recruits`].
+    ///
+    /// Assumes `flower_patches` is sorted from best to worst. That is by
+    /// fitness, except in the first cycle after
+    /// [`Checkpoint::with_solutions`], where the given [`Colony::Flower`]s
+    /// come first.
     fn waggle_dance(
-        flower_patches: &mut [FlowerPatch<N_SCOUTS, Self>; N_SCOUTS],
+        config: &BeesConfig,
+        flower_patches: &mut [FlowerPatch<Self>],
     ) {
-        #[expect(
-            clippy::needless_range_loop,
-            reason = "Codegen is better like this, actually."
-        )]
+        for (index, flower_patch) in flower_patches
+            .iter_mut()
+            .take(config.best_sites)
+            .enumerate()
+        {
+            flower_patch.foragers = if index < config.elite_sites {
+                config.elite_recruits
+            } else {
+                config.best_recruits
+            };
+        }
+    }
+
+    /// The bees algorithm in full, with the parameters given by `config`. To
+    /// use the associated constants of this [`Colony`], pass
+    /// [`Colony::CONFIG`]. Every [`Event`] along the way is passed to
+    /// `observer`. Returns the [`Scout`] with the current best
+    /// [`Colony::Flower`].
+    ///
+    /// `rng` is only used to get started. Every cycle then gets a new `R`,
+    /// seeded from the cycle before it, which is what lets a [`Checkpoint`]
+    /// be resumed exactly.
+    fn bees<R: Rng + SeedableRng, O: Observer<Self>>(
+        &mut self,
+        config: &BeesConfig,
+        rng: &mut R,
+        observer: &mut O,
+    ) -> Scout<Self>
+    where
+        StandardUniform: Distribution<Self::Flower>,
+    {
+        self.resume::<R, O>(Checkpoint::new(config, rng), observer)
+    }
+
+    /// Continues the bees algorithm from a [`Checkpoint`], with the
+    /// parameters it was saved with. Every [`Event`] along the way is passed
+    /// to `observer`. Returns the [`Scout`] with the current best
+    /// [`Colony::Flower`].
+    ///
+    /// As long as `R` is the same type of random number generator as before,
+    /// and [`Colony::stopping_condition`] decides from the [`Progress`] alone,
+    /// the search carries on exactly as it would have if it had never
+    /// stopped.
+    fn resume<R: Rng + SeedableRng, O: Observer<Self>>(
+        &mut self,
+        checkpoint: Checkpoint<Self>,
+        observer: &mut O,
+    ) -> Scout<Self>
+    where
+        StandardUniform: Distribution<Self::Flower>,
+    {
+        let config: BeesConfig = *checkpoint.config();
+        let config: &BeesConfig = &config;
+        search(
+            self,
+            checkpoint,
+            observer,
+            |best_sites: &mut [FlowerPatch<Self>],
+             current_best: &mut Scout<Self>,
+             rng: &mut R,
+             observer: &mut O| {
+                let mut abandoned_sites: usize = 0;
+                for flower_patch in best_sites {
+                    let found: Vec<Scout<Self>> =
+                        flower_patch.local_search(config, current_best, rng);
+                    let abandoned: Option<Scout<Self>> =
+                        flower_patch.abandonment(config, rng);
+                    let shrunk: bool = flower_patch.shrinking();
+                    abandoned_sites = abandoned_sites
+                        .saturating_add(usize::from(abandoned.is_some()));
+                    report(
+                        flower_patch,
+                        found,
+                        abandoned,
+                        shrunk,
+                        current_best,
+                        observer,
+                    );
+                }
+                abandoned_sites
+            },
+        )
+    }
+
+    /// The bees algorithm in full, with the local search of the best sites
+    /// spread across up to `threads` threads. Every [`Event`] along the way is
+    /// passed to `observer`. Returns the [`Scout`] with the current best
+    /// [`Colony::Flower`].
+    ///
+    /// At the start of every cycle, each [`FlowerPatch`] is given its own
+    /// random number generator seeded from that of the cycle, and searches
+    /// against the current best as it was at that point. Abandoned sites are
+    /// only compared against the current best once every thread is done. So,
+    /// for the same `rng`, the result is the same no matter how many threads
+    /// are used, but it is not the same as that of [`Colony::bees`].
+    ///
+    /// # Example
+    ///
+    /// Here, `Ones` is a [`Colony`] that looks for a [`u64`] with as few ones
+    /// as possible, and stops after the given number of cycles.
+    ///
+    /// ```rust
+    /// use core::num::NonZeroUsize;
+    ///
+    /// let config: BeesConfig = BeesConfig::new(8, 1, 4, 3, 2, 64, 3)?;
+    /// let run = |threads: usize| -> Scout<Ones> {
+    ///     let threads: NonZeroUsize = NonZeroUsize::new(threads).unwrap();
+    ///     let mut rng: SmallRng = SmallRng::seed_from_u64(13);
+    ///     Ones(50).bees_parallel(&config, &mut rng, threads, &mut ())
+    /// };
+    ///
+    /// assert_eq!(run(1), run(2));
+    /// assert_eq!(run(1), run(8));
+    #[cfg_attr(doc, doc = include_str!("bees/ones.md"))]
+    fn bees_parallel<R: Rng + SeedableRng + Send, O: Observer<Self>>(
+        &mut self,
+        config: &BeesConfig,
+        rng: &mut R,
+        threads: NonZeroUsize,
+        observer: &mut O,
+    ) -> Scout<Self>
+    where
+        Self: Send + Sync,
+        Self::Flower: Send + Sync,
+        Self::Nectar: Send + Sync,
+        StandardUniform: Distribution<Self::Flower>,
+    {
+        self.resume_parallel::<R, O>(
+            Checkpoint::new(config, rng),
+            threads,
+            observer,
+        )
+    }
+
+    /// Continues the bees algorithm from a [`Checkpoint`] like
+    /// [`Colony::resume`], with the local search of the best sites spread
+    /// across up to `threads` threads like [`Colony::bees_parallel`].
+    fn resume_parallel<R: Rng + SeedableRng + Send, O: Observer<Self>>(
+        &mut self,
+        checkpoint: Checkpoint<Self>,
+        threads: NonZeroUsize,
+        observer: &mut O,
+    ) -> Scout<Self>
+    where
+        Self: Send + Sync,
+        Self::Flower: Send + Sync,
+        Self::Nectar: Send + Sync,
+        StandardUniform: Distribution<Self::Flower>,
+    {
+        let config: BeesConfig = *checkpoint.config();
+        let config: &BeesConfig = &config;
+        search(
+            self,
+            checkpoint,
+            observer,
+            |best_sites: &mut [FlowerPatch<Self>],
+             current_best: &mut Scout<Self>,
+             rng: &mut R,
+             observer: &mut O| {
+                let mut rngs: Vec<R> =
+                    iter::repeat_with(|| R::seed_from_u64(rng.random::<u64>()))
+                        .take(best_sites.len())
+                        .collect();
+                let start: Scout<Self> = *current_best;
+                let mut outcomes: Vec<Outcome<Self>> =
+                    vec![(Vec::new(), None, false); best_sites.len()];
+                let chunk: usize =
+                    best_sites.len().div_ceil(threads.get()).max(1);
+
+                thread::scope(|scope: &Scope<'_, '_>| {
+                    for ((flower_patches, rngs), outcomes) in best_sites
+                        .chunks_mut(chunk)
+                        .zip(rngs.chunks_mut(chunk))
+                        .zip(outcomes.chunks_mut(chunk))
+                    {
+                        let _: ScopedJoinHandle<'_, ()> =
+                            scope.spawn(move || {
+                                for ((flower_patch, rng), outcome) in
+                                    flower_patches
+                                        .iter_mut()
+                                        .zip(rngs)
+                                        .zip(outcomes)
+                                {
+                                    *outcome = (
+                                        flower_patch
+                                            .local_search(config, &start, rng),
+                                        flower_patch.abandonment(config, rng),
+                                        flower_patch.shrinking(),
+                                    );
+                                }
+                            });
+                    }
+                });
+
+                let mut abandoned_sites: usize = 0;
+                for (flower_patch, (found, abandoned, shrunk)) in
+                    best_sites.iter().zip(outcomes)
+                {
+                    abandoned_sites = abandoned_sites
+                        .saturating_add(usize::from(abandoned.is_some()));
+                    report(
+                        flower_patch,
+                        found,
+                        abandoned,
+                        shrunk,
+                        current_best,
+                        observer,
+                    );
+                }
+                abandoned_sites
+            },
+        )
+    }
+}
+
+/// What came of the local search of a [`FlowerPatch`] on another thread: every
+/// [`Scout`] its foragers found, the [`Scout`] it abandoned, if any, and
+/// whether it shrunk.
+type Outcome<Hive> = (Vec<Scout<Hive>>, Option<Scout<Hive>>, bool);
+
+/// Makes `scout` the `current_best` if it is better, letting `observer` know
+/// with an [`Event::NewBest`].
+fn offer<Hive, O>(
+    current_best: &mut Scout<Hive>,
+    scout: Scout<Hive>,
+    observer: &mut O,
+) where
+    Hive: Colony,
+    O: Observer<Hive>,
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    if scout.fitness < current_best.fitness {
+        *current_best = scout;
+        observer.observe(Event::NewBest(scout));
+    }
+}
+
+/// Lets `observer` know how the local search of `flower_patch` went.
+///
+/// That is every [`Scout`] its foragers `found`, the [`Scout`] it abandoned,
+/// if any, and whether it shrunk. An abandoned [`Scout`] is offered as the new
+/// `current_best`.
+fn report<Hive, O>(
+    flower_patch: &FlowerPatch<Hive>,
+    found: Vec<Scout<Hive>>,
+    abandoned: Option<Scout<Hive>>,
+    shrunk: bool,
+    current_best: &mut Scout<Hive>,
+    observer: &mut O,
+) where
+    Hive: Colony,
+    O: Observer<Hive>,
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    for scout in found {
+        observer.observe(Event::Evaluated(scout));
+    }
+    if let Some(scout) = abandoned {
+        observer.observe(Event::PatchAbandoned(scout));
+        observer.observe(Event::Evaluated(flower_patch.scout));
+        offer(current_best, scout, observer);
+    }
+    if shrunk {
+        observer.observe(Event::NeighbourhoodShrunk {
+            scout: flower_patch.scout,
+            neighbourhood: flower_patch.neighbourhood,
+        });
+    }
+}
+
+/// Runs the bees algorithm for `hive`, carrying on from `checkpoint`. Returns
+/// the [`Scout`] with the current best [`Colony::Flower`].
+///
+/// Every cycle, `local_searches` is given the [`BeesConfig::best_sites`] best
+/// [`FlowerPatch`]es to search locally, abandon and shrink, along with the
+/// random number generator of the cycle, and is expected to pass any
+/// [`Event`]s that come of it to `observer`. It returns how many of them were
+/// abandoned, each of which cost an evaluation to replace.
+fn search<Hive, R, O, F>(
+    hive: &mut Hive,
+    mut checkpoint: Checkpoint<Hive>,
+    observer: &mut O,
+    mut local_searches: F,
+) -> Scout<Hive>
+where
+    Hive: Colony,
+    R: Rng + SeedableRng,
+    O: Observer<Hive>,
+    F: FnMut(
+        &mut [FlowerPatch<Hive>],
+        &mut Scout<Hive>,
+        &mut R,
+        &mut O,
+    ) -> usize,
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    // Rejects invalid associated constants at compile time, even though the
+    // search itself only follows the configuration of the checkpoint.
+    let _: BeesConfig = Hive::CONFIG;
+
+    let config: BeesConfig = checkpoint.config;
+    if checkpoint.progress.iterations() == 0 {
+        for scout in checkpoint.scouts() {
+            observer.observe(Event::Evaluated(scout));
+        }
+    }
+    let started: Instant = Instant::now();
+    let elapsed: Duration = checkpoint.progress.elapsed();
+    while !hive.stopping_condition(&checkpoint.progress) {
+        let mut rng: R = R::seed_from_u64(checkpoint.seed);
+        let flower_patches: &mut Vec<FlowerPatch<Hive>> =
+            &mut checkpoint.flower_patches;
+        let current_best: &mut Scout<Hive> = &mut checkpoint.current_best;
+        Hive::waggle_dance(&config, flower_patches);
+
         #[expect(
             clippy::indexing_slicing,
-            reason = "index is correct if invariants are upheld"
+            reason = "a valid BeesConfig always has more scouts than best
+            sites"
         )]
-        for index in 0..Self::ELITE_SITES {
-            flower_patches[index].foragers = Self::ELITE_RECRUITS;
+        let best_sites: &mut [FlowerPatch<Hive>] =
+            &mut flower_patches[..config.best_sites];
+        let foragers: usize =
+            best_sites.iter().fold(0, |foragers: usize, flower_patch| {
+                foragers.saturating_add(flower_patch.foragers)
+            });
+        let abandoned_sites: usize =
+            local_searches(best_sites, current_best, &mut rng, observer);
+
+        let solutions: Vec<Hive::Flower> = iter::repeat_with(|| rng.random())
+            .take(config.scouts.saturating_sub(config.best_sites))
+            .collect();
+        for (flower_patch, scout) in flower_patches
+            .iter_mut()
+            .skip(config.best_sites)
+            .zip(Scout::with_solutions(solutions))
+        {
+            observer.observe(Event::Evaluated(scout));
+            *flower_patch = FlowerPatch::with_scout(&config, scout);
         }
 
-        #[expect(
-            clippy::needless_range_loop,
-            reason = "Codegen is better like this, actually."
-        )]
+        // Sorting at the end of the cycle rather than the start leaves the
+        // first cycle to the order the checkpoint was made with, so that
+        // seeded solutions are searched even if random ones beat them.
+        flower_patches.sort_by_key(|flower_patch| flower_patch.scout.fitness);
         #[expect(
             clippy::indexing_slicing,
-            reason = "index is correct if invariants are upheld"
+            reason = "a valid BeesConfig always has more scouts than best
+            sites, so there is at least one"
         )]
-        for index in Self::ELITE_SITES..Self::BEST_SITES {
-            flower_patches[index].foragers = Self::BEST_RECRUITS;
+        offer(current_best, flower_patches[0].scout, observer);
+
+        checkpoint.seed = rng.random::<u64>();
+        checkpoint.progress.finish_iteration(
+            foragers.saturating_add(abandoned_sites).saturating_add(
+                config.scouts.saturating_sub(config.best_sites),
+            ),
+            checkpoint.current_best.fitness,
+            elapsed.saturating_add(started.elapsed()),
+        );
+        observer.observe(Event::IterationFinished {
+            iteration: checkpoint.progress.iterations(),
+            best: checkpoint.current_best,
+        });
+        observer.checkpoint(&checkpoint);
+    }
+    checkpoint.current_best
+}
+
+/// The parameters of a run of the bees algorithm. Unlike the associated
+/// constants of a [`Colony`], these can be chosen at runtime, such as from the
+/// command line.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::bees::{BeesConfig, ConfigError};
+///
+/// let config: BeesConfig = BeesConfig::new(60, 5, 55, 10, 5, 625, 21)?;
+/// assert_eq!(config.scouts(), 60);
+/// assert_eq!(config.minimum_radius(), 11);
+///
+/// assert_eq!(
+///     BeesConfig::new(60, 5, 60, 10, 5, 625, 21),
+///     Err(ConfigError::NotEnoughScouts {
+///         best_sites: 60,
+///         scouts: 60
+///     })
+/// );
+/// # Ok::<(), ConfigError>(())
+/// ```
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "a bare Config is too vague once it has been imported elsewhere"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BeesConfig {
+    /// Commonly denoted `ns`, the number of scout bees.
+    scouts: usize,
+    /// Commonly denoted `ne`, the number of elite sites.
+    elite_sites: usize,
+    /// Commonly denoted `nb`, the number of best sites.
+    best_sites: usize,
+    /// Commonly denoted `nre`, the number of bees recruited for elite sites.
+    elite_recruits: usize,
+    /// Commonly denoted `nrb`, the number of bees recruited for best sites.
+    best_recruits: usize,
+    /// Commonly denoted `a(0)`, the initial size of a flower patch.
+    flower_patch_size: usize,
+    /// Commonly denoted `stlim`, the limit of stagnation cycles for site
+    /// abandonment.
+    stagnation_limit: usize,
+}
+
+impl BeesConfig {
+    /// Creates a new [`BeesConfig`], checking that the parameters make sense
+    /// together. Each parameter has the same meaning as the matching
+    /// associated constant of [`Colony`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::NotEnoughScouts`] if `scouts` is not larger
+    /// than `best_sites`.
+    ///
+    /// Returns [`ConfigError::TooManyEliteSites`] if `elite_sites` is not
+    /// smaller than `best_sites`.
+    ///
+    /// Returns [`ConfigError::TooFewEliteRecruits`] if `elite_recruits` is
+    /// smaller than `best_recruits`.
+    pub const fn new(
+        scouts: usize,
+        elite_sites: usize,
+        best_sites: usize,
+        elite_recruits: usize,
+        best_recruits: usize,
+        flower_patch_size: usize,
+        stagnation_limit: usize,
+    ) -> Result<Self, ConfigError> {
+        if best_sites >= scouts {
+            return Err(ConfigError::NotEnoughScouts { best_sites, scouts });
+        }
+        if elite_sites >= best_sites {
+            return Err(ConfigError::TooManyEliteSites {
+                elite_sites,
+                best_sites,
+            });
         }
+        if elite_recruits < best_recruits {
+            return Err(ConfigError::TooFewEliteRecruits {
+                elite_recruits,
+                best_recruits,
+            });
+        }
+        Ok(Self {
+            scouts,
+            elite_sites,
+            best_sites,
+            elite_recruits,
+            best_recruits,
+            flower_patch_size,
+            stagnation_limit,
+        })
     }
 
-    /// The bees algorithm in full. Returns the [`Scout`] with the current best
-    /// [`Colony::Flower`].
-    fn bees<R: Rng>(&mut self, rng: &mut R) -> Scout<N_SCOUTS, Self>
-    where
-        StandardUniform: Distribution<Self::Flower>,
-    {
-        let mut flower_patches: [FlowerPatch<N_SCOUTS, Self>; N_SCOUTS] =
-            array::from_fn(|_| FlowerPatch::new(rng));
+    /// Gets the number of scout bees, commonly denoted `ns`.
+    pub const fn scouts(&self) -> usize {
+        self.scouts
+    }
 
-        flower_patches.sort_by_key(|flower_patch| flower_patch.scout.fitness);
+    /// Gets the number of elite sites, commonly denoted `ne`.
+    pub const fn elite_sites(&self) -> usize {
+        self.elite_sites
+    }
 
-        let mut current_best: Scout<N_SCOUTS, Self> = flower_patches[0].scout;
+    /// Gets the number of best sites, commonly denoted `nb`.
+    pub const fn best_sites(&self) -> usize {
+        self.best_sites
+    }
 
-        while !self.stopping_condition() {
-            flower_patches
-                .sort_by_key(|flower_patch| flower_patch.scout.fitness);
+    /// Gets the number of bees recruited for elite sites, commonly denoted
+    /// `nre`.
+    pub const fn elite_recruits(&self) -> usize {
+        self.elite_recruits
+    }
 
-            if flower_patches[0].scout.fitness < current_best.fitness {
-                current_best = flower_patches[0].scout;
-                println!(
-                    "New best:\n\tFitness:{}\n\tSolution:\n{}",
-                    current_best.fitness, current_best.solution
-                );
-            }
-            Self::waggle_dance(&mut flower_patches);
-
-            #[expect(
-                clippy::needless_range_loop,
-                reason = "Codegen is better like this, actually."
-            )]
-            #[expect(
-                clippy::indexing_slicing,
-                reason = "index is correct if invariants are upheld"
-            )]
-            for i in 0..Self::BEST_SITES {
-                flower_patches[i].local_search(&current_best, rng);
-                flower_patches[i].abandonment(&mut current_best, rng);
-                flower_patches[i].shrinking();
-            }
+    /// Gets the number of bees recruited for best sites, commonly denoted
+    /// `nrb`.
+    pub const fn best_recruits(&self) -> usize {
+        self.best_recruits
+    }
 
-            #[expect(
-                clippy::needless_range_loop,
-                reason = "Codegen is better like this, actually."
-            )]
-            #[expect(
-                clippy::indexing_slicing,
-                reason = "index is correct if invariants are upheld"
-            )]
-            for i in Self::BEST_SITES..N_SCOUTS {
-                flower_patches[i] = FlowerPatch::new(rng);
-            }
+    /// Gets the initial size of a flower patch, commonly denoted `a(0)`.
+    pub const fn flower_patch_size(&self) -> usize {
+        self.flower_patch_size
+    }
+
+    /// Gets the limit of stagnation cycles for site abandonment, commonly
+    /// denoted `stlim`.
+    pub const fn stagnation_limit(&self) -> usize {
+        self.stagnation_limit
+    }
+
+    /// Due to integer math, there is a lower limit on the "radius" seen at
+    /// runtime which may be higher than 0. This is the runtime equivalent of
+    /// [`Colony::MINIMUM_RADIUS`].
+    pub const fn minimum_radius(&self) -> usize {
+        let mut size: usize = self.flower_patch_size;
+        let mut i: usize = 0;
+        while i < self.stagnation_limit {
+            size = size.saturating_sub((size.div_euclid(10)).saturating_mul(2));
+            i = i.saturating_add(1);
         }
-        current_best
+        size
     }
 }
 
+/// An error encountered while creating a [`BeesConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConfigError {
+    /// There must be more scouts than best sites.
+    NotEnoughScouts {
+        /// The number of best sites.
+        best_sites: usize,
+        /// The offending number of scouts.
+        scouts: usize,
+    },
+    /// There must be fewer elite sites than best sites.
+    TooManyEliteSites {
+        /// The offending number of elite sites.
+        elite_sites: usize,
+        /// The number of best sites.
+        best_sites: usize,
+    },
+    /// Elite sites must recruit at least as many bees as best sites.
+    TooFewEliteRecruits {
+        /// The offending number of bees recruited for elite sites.
+        elite_recruits: usize,
+        /// The number of bees recruited for best sites.
+        best_recruits: usize,
+    },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::NotEnoughScouts { best_sites, scouts } => write!(
+                f,
+                "there must be more scouts than best sites, but there are \
+                {scouts} scouts and {best_sites} best sites"
+            ),
+            Self::TooManyEliteSites {
+                elite_sites,
+                best_sites,
+            } => write!(
+                f,
+                "there must be fewer elite sites than best sites, but there \
+                are {elite_sites} elite sites and {best_sites} best sites"
+            ),
+            Self::TooFewEliteRecruits {
+                elite_recruits,
+                best_recruits,
+            } => write!(
+                f,
+                "elite sites must recruit at least as many bees as best \
+                sites, but they recruit {elite_recruits} and {best_recruits}"
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
 /// Each [`Scout`] is a bee who has found a [`Colony::Flower`]. It is evaluated
 /// immediately when the [`Scout`] is created.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Scout<const NS: usize, Hive: Colony<NS>>
+pub struct Scout<Hive: Colony>
 where
     StandardUniform: Distribution<Hive::Flower>,
 {
@@ -211,7 +806,7 @@ where
     _hive: PhantomData<Hive>,
 }
 
-impl<const NS: usize, Hive: Colony<NS>> Scout<NS, Hive>
+impl<Hive: Colony> Scout<Hive>
 where
     StandardUniform: Distribution<Hive::Flower>,
 {
@@ -225,6 +820,21 @@ where
         }
     }
 
+    /// Create a new [`Scout`] for each of the given [`Colony::Flower`]s,
+    /// evaluating them all at once with [`Colony::evaluate_batch`].
+    pub(crate) fn with_solutions(
+        solutions: Vec<Hive::Flower>,
+    ) -> impl Iterator<Item = Self> {
+        let fitnesses: Vec<Hive::Nectar> = Hive::evaluate_batch(&solutions);
+        solutions.into_iter().zip(fitnesses).map(
+            |(solution, fitness): (Hive::Flower, Hive::Nectar)| Self {
+                fitness,
+                solution,
+                _hive: PhantomData,
+            },
+        )
+    }
+
     /// Get the recorded fitness of the held [`Colony::Flower`].
     pub const fn fitness(&self) -> Hive::Nectar {
         self.fitness
@@ -239,13 +849,13 @@ where
 /// Each [`FlowerPatch`] describes a "neighbourhood", enabling more exploitative
 /// local searching.
 #[derive(Debug, Clone, Copy)]
-pub struct FlowerPatch<const NS: usize, Hive: Colony<NS>>
+pub struct FlowerPatch<Hive: Colony>
 where
     StandardUniform: Distribution<Hive::Flower>,
 {
     /// The [`Scout`] with the current best [`Colony::Flower`] in this
     /// [`FlowerPatch`].
-    scout: Scout<NS, Hive>,
+    scout: Scout<Hive>,
     /// The number of foragers recruited to this [`FlowerPatch`].
     foragers: usize,
     /// The "radius" of this [`FlowerPatch`], which is the "distance" a
@@ -259,22 +869,33 @@ where
     stagnation_counter: usize,
 }
 
-impl<const NS: usize, Hive: Colony<NS>> FlowerPatch<NS, Hive>
+impl<Hive: Colony> FlowerPatch<Hive>
 where
     StandardUniform: Distribution<Hive::Flower>,
 {
     /// Create a new [`FlowerPatch`] with a randomly assigned [`Scout`].
-    pub(crate) fn new<R: Rng>(rng: &mut R) -> Self {
-        Self::with_solution(rng.random())
+    pub(crate) fn new<R: Rng>(config: &BeesConfig, rng: &mut R) -> Self {
+        Self::with_solution(config, rng.random())
     }
 
     /// Create a new [`FlowerPatch`] with a [`Scout`] assigned to the given
     /// [`Colony::Flower`].
-    pub(crate) fn with_solution(solution: Hive::Flower) -> Self {
+    pub(crate) fn with_solution(
+        config: &BeesConfig,
+        solution: Hive::Flower,
+    ) -> Self {
+        Self::with_scout(config, Scout::with_solution(solution))
+    }
+
+    /// Create a new [`FlowerPatch`] around the given [`Scout`].
+    pub(crate) const fn with_scout(
+        config: &BeesConfig,
+        scout: Scout<Hive>,
+    ) -> Self {
         Self {
-            scout: Scout::with_solution(solution),
+            scout,
             foragers: 0,
-            neighbourhood: Hive::FLOWER_PATCH_SIZE,
+            neighbourhood: config.flower_patch_size,
             stagnation: true,
             stagnation_counter: 0,
         }
@@ -282,65 +903,428 @@ where
 
     /// Promotes the given [`Scout`] to be the new defining [`Scout`] for this
     /// [`FlowerPatch`].
-    pub(crate) const fn promote(&mut self, new_scout: Scout<NS, Hive>) {
+    pub(crate) const fn promote(&mut self, new_scout: Scout<Hive>) {
         self.scout = new_scout;
         self.stagnation = false;
     }
 
-    /// Have the foragers explore nearby [`Colony::Flower`]s.
+    /// Have the foragers explore nearby [`Colony::Flower`]s. Every forager
+    /// explores around the [`Scout`] this [`FlowerPatch`] started the cycle
+    /// with, and their finds are evaluated together with
+    /// [`Colony::evaluate_batch`]. Returns the [`Scout`] of every find.
     pub(crate) fn local_search<R: Rng>(
         &mut self,
-        current_best: &Scout<NS, Hive>,
+        config: &BeesConfig,
+        current_best: &Scout<Hive>,
         rng: &mut R,
-    ) {
+    ) -> Vec<Scout<Hive>> {
         self.stagnation = true;
-        for _ in 0..self.foragers {
-            let new_solution = Hive::explore(
+        let solutions: Vec<Hive::Flower> = iter::repeat_with(|| {
+            Hive::explore(
                 &self.scout.solution,
                 current_best,
                 self.neighbourhood,
+                config,
                 rng,
-            );
-            let new_scout: Scout<NS, Hive> = Scout::with_solution(new_solution);
+            )
+        })
+        .take(self.foragers)
+        .collect();
+        let found: Vec<Scout<Hive>> =
+            Scout::with_solutions(solutions).collect();
+        for &new_scout in &found {
             if new_scout.fitness < self.scout.fitness {
                 self.promote(new_scout);
             }
         }
+        found
     }
 
     /// Shrink the size of this [`FlowerPatch`] if no local improvement was made
-    /// this cycle.
-    pub(crate) const fn shrinking(&mut self) {
+    /// this cycle. Returns whether it was shrunk.
+    pub(crate) const fn shrinking(&mut self) -> bool {
         if self.stagnation {
             self.neighbourhood = self.neighbourhood.saturating_sub(
                 (self.neighbourhood.div_euclid(10)).saturating_mul(2),
             );
         }
+        self.stagnation
     }
 
     /// If no local improvement was made this cycle, increment the stagnation
-    /// counter. If it reaches [`Colony::STAGNATION_LIMIT`], this
+    /// counter. If it reaches [`BeesConfig::stagnation_limit`], this
     /// [`FlowerPatch`] is abandoned and replaced with a new global search.
+    /// Returns the [`Scout`] of the abandoned [`FlowerPatch`], if any.
     pub(crate) fn abandonment<R: Rng>(
         &mut self,
-        current_best: &mut Scout<NS, Hive>,
+        config: &BeesConfig,
         rng: &mut R,
-    ) {
-        if self.stagnation {
-            if self.stagnation_counter < Hive::STAGNATION_LIMIT {
-                self.stagnation_counter =
-                    self.stagnation_counter.saturating_add(1);
-            } else {
-                if self.scout.fitness < current_best.fitness {
-                    *current_best = self.scout;
-                    // Just in case...
-                    println!(
-                        "New best during abandonment:\n\tFitness:{}\n\tSolution:\n{}",
-                        current_best.fitness, current_best.solution
-                    );
+    ) -> Option<Scout<Hive>> {
+        if !self.stagnation {
+            return None;
+        }
+        if self.stagnation_counter < config.stagnation_limit {
+            self.stagnation_counter = self.stagnation_counter.saturating_add(1);
+            return None;
+        }
+        let abandoned: Scout<Hive> = self.scout;
+        *self = Self::new(config, rng);
+        Some(abandoned)
+    }
+}
+
+/// Everything needed to carry on with a run of the bees algorithm from the end
+/// of a cycle.
+///
+/// That is its [`BeesConfig`], every [`FlowerPatch`], the current best
+/// [`Scout`], the [`Progress`] so far and the seed of the next cycle's random
+/// number generator.
+///
+/// An [`Observer`] is handed the [`Checkpoint`] at the end of every cycle. It
+/// can be written out with its [`Display`] implementation, read back with its
+/// [`FromStr`](core::str::FromStr) implementation, and carried on with
+/// [`Colony::resume`] or [`Colony::resume_parallel`]. Only the
+/// [`Colony::Flower`]s are written, so they are evaluated again when read.
+///
+/// # Example
+///
+/// Here, `Ones` is a [`Colony`] that looks for a [`u64`] with as few ones as
+/// possible, and stops after the given number of cycles.
+///
+/// ```rust
+/// use dandelifeon::bees::{Checkpoint, Event, Observer};
+///
+/// /// Saves the [`Checkpoint`] of the fourth cycle.
+/// struct Saver(String);
+///
+/// impl Observer<Ones> for Saver {
+///     fn observe(&mut self, _: Event<Ones>) {}
+///
+///     fn checkpoint(&mut self, checkpoint: &Checkpoint<Ones>) {
+///         if checkpoint.progress().iterations() == 4 {
+///             self.0 = checkpoint.to_string();
+///         }
+///     }
+/// }
+///
+/// let config: BeesConfig = BeesConfig::new(8, 1, 4, 3, 2, 64, 3)?;
+/// let mut rng: SmallRng = SmallRng::seed_from_u64(19);
+/// let mut saver: Saver = Saver(String::new());
+/// let best: Scout<Ones> = Ones(10).bees(&config, &mut rng, &mut saver);
+///
+/// // Carrying on from the fourth cycle ends up exactly where the search did.
+/// let checkpoint: Checkpoint<Ones> = saver.0.parse()?;
+/// assert_eq!(checkpoint.progress().iterations(), 4);
+/// let resumed: Scout<Ones> =
+///     Ones(10).resume::<SmallRng, ()>(checkpoint, &mut ());
+/// assert_eq!(resumed, best);
+#[cfg_attr(doc, doc = include_str!("bees/ones.md"))]
+#[derive(Debug, Clone)]
+pub struct Checkpoint<Hive: Colony>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    /// The parameters of the run.
+    config: BeesConfig,
+    /// Every [`FlowerPatch`], in the order they were left in.
+    flower_patches: Vec<FlowerPatch<Hive>>,
+    /// The [`Scout`] with the current best [`Colony::Flower`].
+    current_best: Scout<Hive>,
+    /// How far the run has come.
+    progress: Progress<Hive::Nectar>,
+    /// The seed of the random number generator for the next cycle.
+    seed: u64,
+}
+
+impl<Hive: Colony> Checkpoint<Hive>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    /// Create the [`Checkpoint`] of a run that has not started yet, with
+    /// [`BeesConfig::scouts`] randomly assigned [`FlowerPatch`]es.
+    pub(crate) fn new<R: Rng>(config: &BeesConfig, rng: &mut R) -> Self {
+        Self::with_solutions(config, [], rng)
+    }
+
+    /// Create the [`Checkpoint`] of a run that has not started yet, whose
+    /// first [`FlowerPatch`]es are assigned the given [`Colony::Flower`]s,
+    /// such as the bests of earlier runs. The rest are randomly assigned, and
+    /// any [`Colony::Flower`]s beyond [`BeesConfig::scouts`] are ignored.
+    ///
+    /// The given [`Colony::Flower`]s are kept ahead of the random ones until
+    /// the end of the first cycle, so the first [`BeesConfig::best_sites`] of
+    /// them are searched locally even if random ones are better.
+    ///
+    /// Pass it to [`Colony::resume`] or [`Colony::resume_parallel`] to refine
+    /// the given [`Colony::Flower`]s rather than start from scratch.
+    ///
+    /// # Example
+    ///
+    /// Here, `Ones` is a [`Colony`] that looks for a [`u64`] with as few ones
+    /// as possible, and stops after the given number of cycles.
+    ///
+    /// ```rust
+    /// use dandelifeon::bees::Checkpoint;
+    ///
+    /// let config: BeesConfig = BeesConfig::new(8, 1, 4, 3, 2, 64, 3)?;
+    /// let mut rng: SmallRng = SmallRng::seed_from_u64(20);
+    /// let checkpoint: Checkpoint<Ones> =
+    ///     Checkpoint::with_solutions(&config, [0b1000, 0b11], &mut rng);
+    /// assert_eq!(checkpoint.current_best().solution(), 0b1000);
+    ///
+    /// // A random start would be nowhere near this after only 5 cycles.
+    /// let best: Scout<Ones> =
+    ///     Ones(5).resume::<SmallRng, ()>(checkpoint, &mut ());
+    /// assert!(best.fitness() <= 1);
+    #[cfg_attr(doc, doc = include_str!("bees/ones.md"))]
+    pub fn with_solutions<I, R>(
+        config: &BeesConfig,
+        solutions: I,
+        rng: &mut R,
+    ) -> Self
+    where
+        I: IntoIterator<Item = Hive::Flower>,
+        R: Rng,
+    {
+        let mut flower_patches: Vec<FlowerPatch<Hive>> = solutions
+            .into_iter()
+            .take(config.scouts)
+            .map(|solution: Hive::Flower| {
+                FlowerPatch::with_solution(config, solution)
+            })
+            .collect();
+        let seeded: usize = flower_patches.len();
+        flower_patches.extend(
+            iter::repeat_with(|| FlowerPatch::new(config, rng))
+                .take(config.scouts.saturating_sub(seeded)),
+        );
+
+        let (seeds, random): (&mut [FlowerPatch<Hive>], _) =
+            flower_patches.split_at_mut(seeded);
+        seeds.sort_by_key(|flower_patch| flower_patch.scout.fitness);
+        random.sort_by_key(|flower_patch| flower_patch.scout.fitness);
+
+        #[expect(
+            clippy::indexing_slicing,
+            reason = "a valid BeesConfig always has more scouts than best
+            sites, so there is at least one"
+        )]
+        let first: Scout<Hive> = flower_patches[0].scout;
+        let current_best: Scout<Hive> = flower_patches.iter().fold(
+            first,
+            |best: Scout<Hive>, flower_patch: &FlowerPatch<Hive>| {
+                if flower_patch.scout.fitness < best.fitness {
+                    flower_patch.scout
+                } else {
+                    best
                 }
-                *self = Self::new(rng);
+            },
+        );
+
+        Self {
+            config: *config,
+            flower_patches,
+            current_best,
+            progress: Progress::new(config.scouts, current_best.fitness),
+            seed: rng.random::<u64>(),
+        }
+    }
+
+    /// Get the parameters of the run.
+    pub const fn config(&self) -> &BeesConfig {
+        &self.config
+    }
+
+    /// Get the [`Scout`] with the current best [`Colony::Flower`].
+    pub const fn current_best(&self) -> Scout<Hive> {
+        self.current_best
+    }
+
+    /// Get how far the run has come.
+    pub const fn progress(&self) -> &Progress<Hive::Nectar> {
+        &self.progress
+    }
+
+    /// Get the [`Scout`] of every [`FlowerPatch`], in the order they were
+    /// left in.
+    pub fn scouts(&self) -> impl Iterator<Item = Scout<Hive>> {
+        self.flower_patches
+            .iter()
+            .map(|flower_patch: &FlowerPatch<Hive>| flower_patch.scout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Checks that seeded [`Colony::Flower`]s are searched, and that resumed
+    //! searches carry on exactly.
+
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng as _};
+
+    use crate::Hive;
+    use crate::bees::{
+        BeesConfig, Checkpoint, Colony, Event, MaxIterations, Observer,
+        Progress, Scout,
+    };
+
+    /// A [`Hive`] that stops after a number of cycles.
+    type Limited = Hive<MaxIterations>;
+
+    /// A [`Colony`] whose foragers never find anything new, so that its
+    /// [`FlowerPatch`]es keep their [`Scout`]s until they are replaced.
+    ///
+    /// [`FlowerPatch`]: crate::bees::FlowerPatch
+    #[derive(Debug, Clone, Copy)]
+    struct Still;
+
+    impl Colony for Still {
+        type Flower = u64;
+        type Nectar = u32;
+
+        const SCOUTS: usize = 8;
+        const ELITE_SITES: usize = 1;
+        const BEST_SITES: usize = 4;
+        const ELITE_RECRUITS: usize = 3;
+        const BEST_RECRUITS: usize = 2;
+        const FLOWER_PATCH_SIZE: usize = 64;
+        const STAGNATION_LIMIT: usize = 3;
+
+        fn evaluate(solution: &u64) -> u32 {
+            solution.count_ones()
+        }
+
+        fn explore<R: Rng>(
+            origin: &u64,
+            _current_best: &Scout<Self>,
+            _radius: usize,
+            _config: &BeesConfig,
+            _rng: &mut R,
+        ) -> u64 {
+            *origin
+        }
+
+        fn stopping_condition(&mut self, progress: &Progress<u32>) -> bool {
+            progress.iterations() >= 1
+        }
+    }
+
+    /// Keeps the [`Colony::Flower`] of every [`Scout`] in the last
+    /// [`Checkpoint`].
+    struct Scouts(Vec<u64>);
+
+    impl Observer<Still> for Scouts {
+        fn observe(&mut self, _event: Event<Still>) {}
+
+        fn checkpoint(&mut self, checkpoint: &Checkpoint<Still>) {
+            self.0 = checkpoint
+                .scouts()
+                .map(|scout: Scout<Still>| scout.solution())
+                .collect();
+        }
+    }
+
+    /// A seed worse than any random [`Colony::Flower`] is still searched in
+    /// the first cycle, rather than being replaced by the global search.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seed is not among the [`Scout`]s after the first cycle.
+    #[test]
+    fn weak_seed_survives_first_cycle() {
+        let mut rng: SmallRng = SmallRng::seed_from_u64(0);
+        let checkpoint: Checkpoint<Still> =
+            Checkpoint::with_solutions(&Still::CONFIG, [u64::MAX], &mut rng);
+        let mut scouts: Scouts = Scouts(Vec::new());
+        let _: Scout<Still> =
+            Still.resume::<SmallRng, _>(checkpoint, &mut scouts);
+        assert!(
+            scouts.0.contains(&u64::MAX),
+            "the seed was replaced in the first cycle"
+        );
+    }
+
+    /// Keeps every [`Event`], and the text of the [`Checkpoint`] of one
+    /// cycle.
+    struct Saver {
+        /// Every [`Event`] observed so far.
+        events: Vec<Event<Limited>>,
+        /// The cycle to keep the [`Checkpoint`] of.
+        iteration: usize,
+        /// The text of the [`Checkpoint`], once it has been made.
+        text: String,
+    }
+
+    impl Observer<Limited> for Saver {
+        fn observe(&mut self, event: Event<Limited>) {
+            self.events.push(event);
+        }
+
+        fn checkpoint(&mut self, checkpoint: &Checkpoint<Limited>) {
+            if checkpoint.progress().iterations() == self.iteration {
+                self.text = checkpoint.to_string();
             }
         }
     }
+
+    /// Reduces `event` to something comparable: which kind of [`Event`] it
+    /// is, the [`Scout`] it is about, and the number it carries, if any.
+    fn summary(event: &Event<Limited>) -> (u8, Scout<Limited>, usize) {
+        match *event {
+            Event::Evaluated(scout) => (0, scout, 0),
+            Event::NewBest(scout) => (1, scout, 0),
+            Event::PatchAbandoned(scout) => (2, scout, 0),
+            Event::NeighbourhoodShrunk {
+                scout,
+                neighbourhood,
+            } => (3, scout, neighbourhood),
+            Event::IterationFinished { iteration, best } => {
+                (4, best, iteration)
+            }
+        }
+    }
+
+    /// A search stopped after its fourth cycle and resumed from the text of
+    /// its [`Checkpoint`] observes the same [`Event`]s, and finds the same
+    /// best, as one that was never stopped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Checkpoint`] cannot be read back, or if the resumed
+    /// search differs from the uninterrupted one.
+    #[test]
+    fn resumed_search_matches_uninterrupted() {
+        let mut rng: SmallRng = SmallRng::seed_from_u64(19);
+        let mut whole: Vec<Event<Limited>> = Vec::new();
+        let best: Scout<Limited> = Hive::new(MaxIterations::new(10)).bees(
+            &Limited::CONFIG,
+            &mut rng,
+            &mut whole,
+        );
+
+        let mut rng: SmallRng = SmallRng::seed_from_u64(19);
+        let mut saver: Saver = Saver {
+            events: Vec::new(),
+            iteration: 4,
+            text: String::new(),
+        };
+        let _: Scout<Limited> = Hive::new(MaxIterations::new(4)).bees(
+            &Limited::CONFIG,
+            &mut rng,
+            &mut saver,
+        );
+        let checkpoint: Checkpoint<Limited> = saver.text.parse().unwrap();
+        let resumed: Scout<Limited> = Hive::new(MaxIterations::new(10))
+            .resume::<SmallRng, _>(checkpoint, &mut saver);
+
+        assert_eq!(resumed, best, "the resumed search found another best");
+        assert!(
+            saver
+                .events
+                .iter()
+                .map(summary)
+                .eq(whole.iter().map(summary)),
+            "the resumed search observed different events"
+        );
+    }
 }
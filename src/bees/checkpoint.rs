@@ -0,0 +1,419 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Writing a [`Checkpoint`] as text and reading it back.
+//!
+//! The format is line-based. A header line naming the format and its version
+//! is followed by one line each for the [`BeesConfig`], the [`Progress`], the
+//! milliseconds spent so far and the seed, as whitespace separated numbers.
+//! The [`BeesConfig`] is written in the order taken by [`BeesConfig::new`],
+//! using the names the associated constants of [`Colony`] are commonly
+//! denoted by. Then comes the current best [`Colony::Flower`], and then every
+//! [`FlowerPatch`], each of which starts with a line holding its
+//! neighbourhood, foragers and stagnation:
+//!
+//! ```text
+//! bees-checkpoint 1
+//! config <ns> <ne> <nb> <nre> <nrb> <a(0)> <stlim>
+//! progress <iterations> <evaluations> <stagnant iterations>
+//! elapsed <milliseconds>
+//! seed <seed>
+//! best <lines>
+//! ...
+//! patch <neighbourhood> <foragers> <stagnation> <stagnation counter> <lines>
+//! ...
+//! ```
+//!
+//! Every [`Colony::Flower`] is written with its [`Display`] implementation,
+//! taking up the number of lines given at the end of the line before it, and
+//! is read back with its [`FromStr`] implementation.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::iter::{self, Enumerate};
+use core::str::{FromStr, Lines};
+use core::time::Duration;
+
+use rand::distr::{Distribution, StandardUniform};
+
+use crate::bees::{
+    BeesConfig, Checkpoint, Colony, ConfigError, FlowerPatch, Progress, Scout,
+};
+
+/// The first word of a [`Checkpoint`], which is followed by [`VERSION`].
+const HEADER: &str = "bees-checkpoint";
+
+/// The version of the format written by this module.
+const VERSION: u32 = 1;
+
+/// An error encountered while parsing a [`Checkpoint`] from text.
+///
+/// Lines are counted from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParseCheckpointError {
+    /// The line the error was found on.
+    line: usize,
+    /// What went wrong.
+    kind: ParseCheckpointErrorKind,
+}
+
+impl ParseCheckpointError {
+    /// Get the line the error was found on.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Get what went wrong.
+    pub const fn kind(&self) -> ParseCheckpointErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ParseCheckpointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl Error for ParseCheckpointError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.kind {
+            ParseCheckpointErrorKind::InvalidConfig(ref error) => Some(error),
+            ParseCheckpointErrorKind::UnknownVersion(_)
+            | ParseCheckpointErrorKind::UnexpectedLine { .. }
+            | ParseCheckpointErrorKind::UnexpectedEnd
+            | ParseCheckpointErrorKind::TrailingText
+            | ParseCheckpointErrorKind::InvalidFlower => None,
+        }
+    }
+}
+
+/// The ways in which parsing a [`Checkpoint`] from text can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParseCheckpointErrorKind {
+    /// The checkpoint was written in a version of the format that is not
+    /// understood.
+    UnknownVersion(u32),
+    /// A line did not have the expected keyword and number of values.
+    UnexpectedLine {
+        /// The keyword the line was expected to start with.
+        expected: &'static str,
+    },
+    /// The text ended before the last [`FlowerPatch`] was read.
+    UnexpectedEnd,
+    /// There was more than blank lines after the last [`FlowerPatch`].
+    TrailingText,
+    /// The parameters do not make a valid [`BeesConfig`].
+    InvalidConfig(ConfigError),
+    /// A [`Colony::Flower`] could not be parsed.
+    InvalidFlower,
+}
+
+impl Display for ParseCheckpointErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::UnknownVersion(version) => {
+                write!(f, "unknown checkpoint version {version}")
+            }
+            Self::UnexpectedLine { expected } => {
+                write!(f, "expected a well-formed '{expected}' line")
+            }
+            Self::UnexpectedEnd => write!(f, "checkpoint ended early"),
+            Self::TrailingText => {
+                write!(f, "expected nothing after the last patch")
+            }
+            Self::InvalidConfig(error) => write!(f, "{error}"),
+            Self::InvalidFlower => write!(f, "could not parse the flower"),
+        }
+    }
+}
+
+impl<Hive: Colony> Display for Checkpoint<Hive>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let config: &BeesConfig = &self.config;
+        writeln!(f, "{HEADER} {VERSION}")?;
+        writeln!(
+            f,
+            "config {} {} {} {} {} {} {}",
+            config.scouts,
+            config.elite_sites,
+            config.best_sites,
+            config.elite_recruits,
+            config.best_recruits,
+            config.flower_patch_size,
+            config.stagnation_limit
+        )?;
+        writeln!(
+            f,
+            "progress {} {} {}",
+            self.progress.iterations(),
+            self.progress.evaluations(),
+            self.progress.stagnant_iterations()
+        )?;
+        writeln!(f, "elapsed {}", self.progress.elapsed().as_millis())?;
+        writeln!(f, "seed {}", self.seed)?;
+
+        let best: String = self.current_best.solution.to_string();
+        writeln!(f, "best {}", best.lines().count())?;
+        write_lines(f, &best)?;
+        for flower_patch in &self.flower_patches {
+            let solution: String = flower_patch.scout.solution.to_string();
+            writeln!(
+                f,
+                "patch {} {} {} {} {}",
+                flower_patch.neighbourhood,
+                flower_patch.foragers,
+                u8::from(flower_patch.stagnation),
+                flower_patch.stagnation_counter,
+                solution.lines().count()
+            )?;
+            write_lines(f, &solution)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Hive: Colony> FromStr for Checkpoint<Hive>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+    Hive::Flower: FromStr,
+{
+    type Err = ParseCheckpointError;
+
+    /// Parses a [`Checkpoint`] from the format emitted by its [`Display`]
+    /// implementation, evaluating every [`Colony::Flower`] again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::Hive;
+    /// use dandelifeon::bees::{
+    ///     Checkpoint, ParseCheckpointError, ParseCheckpointErrorKind,
+    /// };
+    ///
+    /// let text: &str = "bees-checkpoint 1\nconfig 60 5 55 10 5 625\n";
+    /// let error: ParseCheckpointError =
+    ///     text.parse::<Checkpoint<Hive>>().unwrap_err();
+    /// assert_eq!(error.line(), 2);
+    /// assert_eq!(
+    ///     error.kind(),
+    ///     ParseCheckpointErrorKind::UnexpectedLine { expected: "config" }
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cursor: Cursor<'_> = Cursor {
+            line: 0,
+            lines: s.lines().enumerate(),
+        };
+
+        let [version]: [u32; 1] = cursor.record(HEADER)?;
+        if version != VERSION {
+            return Err(
+                cursor.error(ParseCheckpointErrorKind::UnknownVersion(version))
+            );
+        }
+        let [
+            scouts,
+            elite_sites,
+            best_sites,
+            elite_recruits,
+            best_recruits,
+            flower_patch_size,
+            stagnation_limit,
+        ]: [usize; 7] = cursor.record("config")?;
+        let config: BeesConfig = BeesConfig::new(
+            scouts,
+            elite_sites,
+            best_sites,
+            elite_recruits,
+            best_recruits,
+            flower_patch_size,
+            stagnation_limit,
+        )
+        .map_err(|error: ConfigError| {
+            cursor.error(ParseCheckpointErrorKind::InvalidConfig(error))
+        })?;
+        let [iterations, evaluations, stagnant_iterations]: [usize; 3] =
+            cursor.record("progress")?;
+        let [elapsed]: [u64; 1] = cursor.record("elapsed")?;
+        let [seed]: [u64; 1] = cursor.record("seed")?;
+
+        let [lines]: [usize; 1] = cursor.record("best")?;
+        let current_best: Scout<Hive> =
+            Scout::with_solution(cursor.flower(lines)?);
+        let flower_patches: Vec<FlowerPatch<Hive>> =
+            iter::repeat_with(|| read_flower_patch(&mut cursor))
+                .take(config.scouts)
+                .collect::<Result<Vec<FlowerPatch<Hive>>, _>>()?;
+        cursor.end()?;
+
+        Ok(Self {
+            config,
+            flower_patches,
+            current_best,
+            progress: Progress::restore(
+                iterations,
+                evaluations,
+                current_best.fitness,
+                stagnant_iterations,
+                Duration::from_millis(elapsed),
+            ),
+            seed,
+        })
+    }
+}
+
+/// Writes every line of `text`, each ending with a newline.
+///
+/// # Errors
+///
+/// Returns an error if `formatter` does.
+fn write_lines(formatter: &mut Formatter<'_>, text: &str) -> fmt::Result {
+    for line in text.lines() {
+        writeln!(formatter, "{line}")?;
+    }
+    Ok(())
+}
+
+/// Reads a single [`FlowerPatch`], evaluating its [`Colony::Flower`].
+///
+/// # Errors
+///
+/// Returns an error if the next line is not a well-formed `patch` line, or if
+/// the [`Colony::Flower`] after it cannot be read.
+fn read_flower_patch<Hive: Colony>(
+    cursor: &mut Cursor<'_>,
+) -> Result<FlowerPatch<Hive>, ParseCheckpointError>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+    Hive::Flower: FromStr,
+{
+    let [
+        neighbourhood,
+        foragers,
+        stagnation,
+        stagnation_counter,
+        lines,
+    ]: [usize; 5] = cursor.record("patch")?;
+    let stagnation: bool = match stagnation {
+        0 => false,
+        1 => true,
+        _ => {
+            return Err(cursor.error(
+                ParseCheckpointErrorKind::UnexpectedLine { expected: "patch" },
+            ));
+        }
+    };
+    Ok(FlowerPatch {
+        scout: Scout::with_solution(cursor.flower(lines)?),
+        foragers,
+        neighbourhood,
+        stagnation,
+        stagnation_counter,
+    })
+}
+
+/// A cursor over the lines of a [`Checkpoint`], which keeps track of the line
+/// number for error reporting.
+struct Cursor<'text> {
+    /// The number of the line last read, or 0 if none has been.
+    line: usize,
+    /// The lines of the text, numbered from 0.
+    lines: Enumerate<Lines<'text>>,
+}
+
+impl<'text> Cursor<'text> {
+    /// Builds a [`ParseCheckpointError`] at the line last read.
+    const fn error(
+        &self,
+        kind: ParseCheckpointErrorKind,
+    ) -> ParseCheckpointError {
+        ParseCheckpointError {
+            line: self.line,
+            kind,
+        }
+    }
+
+    /// Reads the next line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no lines left.
+    fn next_line(&mut self) -> Result<&'text str, ParseCheckpointError> {
+        let Some((index, line)) = self.lines.next() else {
+            self.line = self.line.saturating_add(1);
+            return Err(self.error(ParseCheckpointErrorKind::UnexpectedEnd));
+        };
+        self.line = index.saturating_add(1);
+        Ok(line)
+    }
+
+    /// Reads a line made up of `keyword` followed by exactly `N` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no lines left, or if the line is not
+    /// `keyword` followed by `N` values that parse as `T`.
+    fn record<T: FromStr, const N: usize>(
+        &mut self,
+        keyword: &'static str,
+    ) -> Result<[T; N], ParseCheckpointError> {
+        let line: &str = self.next_line()?;
+        let mut words = line.split_whitespace();
+        let unexpected: ParseCheckpointError =
+            self.error(ParseCheckpointErrorKind::UnexpectedLine {
+                expected: keyword,
+            });
+        if words.next() != Some(keyword) {
+            return Err(unexpected);
+        }
+        let values: Vec<T> = words
+            .map(str::parse)
+            .collect::<Result<Vec<T>, T::Err>>()
+            .map_err(|_error: T::Err| unexpected)?;
+        values.try_into().map_err(|_values: Vec<T>| unexpected)
+    }
+
+    /// Reads the next `lines` lines as a single [`Colony::Flower`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than `lines` lines left, or if
+    /// they do not parse as an `F`.
+    fn flower<F: FromStr>(
+        &mut self,
+        lines: usize,
+    ) -> Result<F, ParseCheckpointError> {
+        let first: usize = self.line.saturating_add(1);
+        let mut text: String = String::new();
+        for index in 0..lines {
+            if index > 0 {
+                text.push('\n');
+            }
+            text.push_str(self.next_line()?);
+        }
+        text.parse().map_err(|_error: F::Err| ParseCheckpointError {
+            line: first,
+            kind: ParseCheckpointErrorKind::InvalidFlower,
+        })
+    }
+
+    /// Checks that nothing but blank lines is left.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error at the first line that is not blank.
+    fn end(&mut self) -> Result<(), ParseCheckpointError> {
+        while let Some((index, line)) = self.lines.next() {
+            self.line = index.saturating_add(1);
+            if !line.trim().is_empty() {
+                return Err(self.error(ParseCheckpointErrorKind::TrailingText));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,349 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Composable [`StoppingCondition`]s, and the [`Progress`] they are checked
+//! against.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+
+/// How far a run of the bees algorithm has come. This is what
+/// [`Colony::stopping_condition`](crate::bees::Colony::stopping_condition) is
+/// given at the start of every cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Progress<Nectar> {
+    /// The number of cycles finished.
+    iterations: usize,
+    /// The number of times a [`Colony::Flower`](crate::bees::Colony::Flower)
+    /// has been evaluated.
+    evaluations: usize,
+    /// The fitness of the current best
+    /// [`Colony::Flower`](crate::bees::Colony::Flower).
+    best: Nectar,
+    /// The number of cycles finished since the current best last improved.
+    stagnant_iterations: usize,
+    /// The time spent running cycles so far.
+    elapsed: Duration,
+}
+
+impl<Nectar: Copy + Ord> Progress<Nectar> {
+    /// Creates a new [`Progress`] for a run that has made `evaluations`
+    /// evaluations to get started, the best of which was `best`.
+    pub const fn new(evaluations: usize, best: Nectar) -> Self {
+        Self {
+            iterations: 0,
+            evaluations,
+            best,
+            stagnant_iterations: 0,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Creates a [`Progress`] as it was partway through a run, such as when
+    /// resuming from a [`Checkpoint`](crate::bees::Checkpoint).
+    pub(crate) const fn restore(
+        iterations: usize,
+        evaluations: usize,
+        best: Nectar,
+        stagnant_iterations: usize,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            iterations,
+            evaluations,
+            best,
+            stagnant_iterations,
+            elapsed,
+        }
+    }
+
+    /// Gets the number of cycles finished.
+    pub const fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Gets the number of times a
+    /// [`Colony::Flower`](crate::bees::Colony::Flower) has been evaluated.
+    pub const fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// Gets the fitness of the current best
+    /// [`Colony::Flower`](crate::bees::Colony::Flower).
+    pub const fn best(&self) -> Nectar {
+        self.best
+    }
+
+    /// Gets the number of cycles finished since the current best last
+    /// improved.
+    pub const fn stagnant_iterations(&self) -> usize {
+        self.stagnant_iterations
+    }
+
+    /// Gets the time spent running cycles so far. This carries over when
+    /// resuming from a [`Checkpoint`](crate::bees::Checkpoint), so time spent
+    /// stopped is not counted.
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Records a finished cycle that made `evaluations` evaluations and ended
+    /// with `best` as the fitness of the current best, `elapsed` into the run.
+    pub(crate) fn finish_iteration(
+        &mut self,
+        evaluations: usize,
+        best: Nectar,
+        elapsed: Duration,
+    ) {
+        self.iterations = self.iterations.saturating_add(1);
+        self.evaluations = self.evaluations.saturating_add(evaluations);
+        self.elapsed = elapsed;
+        if best < self.best {
+            self.best = best;
+            self.stagnant_iterations = 0;
+        } else {
+            self.stagnant_iterations =
+                self.stagnant_iterations.saturating_add(1);
+        }
+    }
+}
+
+/// Decides when a run of the bees algorithm should stop, based on its
+/// [`Progress`].
+///
+/// [`StoppingCondition`]s can be combined with [`Or`] and [`And`]. An
+/// [`Option`] of a [`StoppingCondition`] is also one, which never stops when
+/// it is [`None`].
+///
+/// Other than [`Cancelled`], the [`StoppingCondition`]s here decide from the
+/// [`Progress`] alone, which is saved in a
+/// [`Checkpoint`](crate::bees::Checkpoint). So a search resumed from one stops
+/// exactly when it would have if it had never stopped.
+///
+/// # Example
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use dandelifeon::bees::{
+///     And, MaxIterations, NoImprovement, Or, Progress, StoppingCondition,
+///     TargetFitness, TimeBudget,
+/// };
+///
+/// let mut condition = Or::new(
+///     Or::new(MaxIterations::new(1000), NoImprovement::new(50)),
+///     TimeBudget::new(Duration::from_secs(3600)),
+/// );
+/// let progress: Progress<u32> = Progress::new(60, 25);
+/// assert!(!condition.should_stop(&progress));
+///
+/// let mut condition = And::new(TargetFitness::new(25), MaxIterations::new(0));
+/// assert!(condition.should_stop(&progress));
+/// ```
+pub trait StoppingCondition<Nectar> {
+    /// Returns `true` when it is time to stop searching.
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool;
+}
+
+impl<Nectar, S: StoppingCondition<Nectar>> StoppingCondition<Nectar>
+    for Option<S>
+{
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool {
+        self.as_mut()
+            .is_some_and(|condition: &mut S| condition.should_stop(progress))
+    }
+}
+
+/// A [`StoppingCondition`] that never stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Never;
+
+impl<Nectar> StoppingCondition<Nectar> for Never {
+    fn should_stop(&mut self, _progress: &Progress<Nectar>) -> bool {
+        false
+    }
+}
+
+/// A [`StoppingCondition`] that stops after a number of cycles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaxIterations(usize);
+
+impl MaxIterations {
+    /// Creates a new [`MaxIterations`] that stops after `iterations` cycles.
+    pub const fn new(iterations: usize) -> Self {
+        Self(iterations)
+    }
+}
+
+impl<Nectar> StoppingCondition<Nectar> for MaxIterations {
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool {
+        progress.iterations >= self.0
+    }
+}
+
+/// A [`StoppingCondition`] that stops once a number of evaluations have been
+/// made. The cycle that crosses the limit is always finished, so a run may
+/// make a few more evaluations than this.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaxEvaluations(usize);
+
+impl MaxEvaluations {
+    /// Creates a new [`MaxEvaluations`] that stops after `evaluations`
+    /// evaluations.
+    pub const fn new(evaluations: usize) -> Self {
+        Self(evaluations)
+    }
+}
+
+impl<Nectar> StoppingCondition<Nectar> for MaxEvaluations {
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool {
+        progress.evaluations >= self.0
+    }
+}
+
+/// A [`StoppingCondition`] that stops once a wall-clock budget has been used
+/// up, going by [`Progress::elapsed`].
+///
+/// The cycle that crosses the limit is always finished, so a run may take a
+/// little longer than this.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeBudget(Duration);
+
+impl TimeBudget {
+    /// Creates a new [`TimeBudget`] that stops once `budget` has passed.
+    pub const fn new(budget: Duration) -> Self {
+        Self(budget)
+    }
+}
+
+impl<Nectar> StoppingCondition<Nectar> for TimeBudget {
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool {
+        progress.elapsed >= self.0
+    }
+}
+
+/// A [`StoppingCondition`] that stops once the current best is at least as
+/// good as a target fitness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TargetFitness<Nectar>(Nectar);
+
+impl<Nectar> TargetFitness<Nectar> {
+    /// Creates a new [`TargetFitness`] that stops once the current best is at
+    /// least as good as `target`.
+    pub const fn new(target: Nectar) -> Self {
+        Self(target)
+    }
+}
+
+impl<Nectar: Ord> StoppingCondition<Nectar> for TargetFitness<Nectar> {
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool {
+        progress.best <= self.0
+    }
+}
+
+/// A [`StoppingCondition`] that stops once the current best has not improved
+/// for a number of cycles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoImprovement(usize);
+
+impl NoImprovement {
+    /// Creates a new [`NoImprovement`] that stops after `iterations` cycles
+    /// in a row without a new best.
+    pub const fn new(iterations: usize) -> Self {
+        Self(iterations)
+    }
+}
+
+impl<Nectar> StoppingCondition<Nectar> for NoImprovement {
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool {
+        progress.stagnant_iterations >= self.0
+    }
+}
+
+/// A [`StoppingCondition`] that stops once a flag has been raised from
+/// elsewhere, such as by another thread or a signal handler.
+///
+/// Since it is checked at the start of every cycle, the cycle that is running
+/// when the flag is raised is always finished.
+///
+/// # Example
+///
+/// ```rust
+/// use core::sync::atomic::{AtomicBool, Ordering};
+///
+/// use dandelifeon::bees::{Cancelled, Progress, StoppingCondition};
+///
+/// static CANCELLED: AtomicBool = AtomicBool::new(false);
+///
+/// let mut condition: Cancelled<'static> = Cancelled::new(&CANCELLED);
+/// let progress: Progress<u32> = Progress::new(60, 25);
+/// assert!(!condition.should_stop(&progress));
+///
+/// CANCELLED.store(true, Ordering::Relaxed);
+/// assert!(condition.should_stop(&progress));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Cancelled<'flag>(&'flag AtomicBool);
+
+impl<'flag> Cancelled<'flag> {
+    /// Creates a new [`Cancelled`] that stops once `flag` is `true`.
+    pub const fn new(flag: &'flag AtomicBool) -> Self {
+        Self(flag)
+    }
+}
+
+impl<Nectar> StoppingCondition<Nectar> for Cancelled<'_> {
+    fn should_stop(&mut self, _progress: &Progress<Nectar>) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Two [`StoppingCondition`]s, stopping as soon as either of them would. Both
+/// are always checked, so that neither misses any [`Progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Or<A, B>(A, B);
+
+impl<A, B> Or<A, B> {
+    /// Combines `first` and `second`, stopping as soon as either of them
+    /// would.
+    pub const fn new(first: A, second: B) -> Self {
+        Self(first, second)
+    }
+}
+
+impl<Nectar, A, B> StoppingCondition<Nectar> for Or<A, B>
+where
+    A: StoppingCondition<Nectar>,
+    B: StoppingCondition<Nectar>,
+{
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool {
+        let first: bool = self.0.should_stop(progress);
+        let second: bool = self.1.should_stop(progress);
+        first || second
+    }
+}
+
+/// Two [`StoppingCondition`]s, stopping only once both of them would. Both
+/// are always checked, so that neither misses any [`Progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct And<A, B>(A, B);
+
+impl<A, B> And<A, B> {
+    /// Combines `first` and `second`, stopping only once both of them would.
+    pub const fn new(first: A, second: B) -> Self {
+        Self(first, second)
+    }
+}
+
+impl<Nectar, A, B> StoppingCondition<Nectar> for And<A, B>
+where
+    A: StoppingCondition<Nectar>,
+    B: StoppingCondition<Nectar>,
+{
+    fn should_stop(&mut self, progress: &Progress<Nectar>) -> bool {
+        let first: bool = self.0.should_stop(progress);
+        let second: bool = self.1.should_stop(progress);
+        first && second
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Events emitted while the bees algorithm runs, and the [`Observer`]s that
+//! receive them.
+
+use rand::distr::{Distribution, StandardUniform};
+
+use crate::bees::{Checkpoint, Colony, Scout};
+
+/// Something that happened during a run of the bees algorithm.
+///
+/// When searching in parallel with [`Colony::bees_parallel`], the events of
+/// every [`FlowerPatch`](crate::bees::FlowerPatch) are held back until the
+/// cycle's local search is done, and are then observed in the same order as
+/// they would be by [`Colony::bees`].
+#[derive(Debug, Clone, Copy)]
+pub enum Event<Hive: Colony>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    /// A [`Colony::Flower`] was evaluated, whether or not it was kept. This
+    /// is observed once for every evaluation, from the first
+    /// [`FlowerPatch`](crate::bees::FlowerPatch)es of a run to the foragers
+    /// of every local search and the scouts of every global search.
+    Evaluated(Scout<Hive>),
+    /// A better [`Colony::Flower`] than any seen so far was found.
+    NewBest(Scout<Hive>),
+    /// A [`FlowerPatch`](crate::bees::FlowerPatch) went without a local
+    /// improvement for too long and was replaced with a new global search.
+    PatchAbandoned(Scout<Hive>),
+    /// A [`FlowerPatch`](crate::bees::FlowerPatch) found no local improvement
+    /// this cycle, so its neighbourhood was shrunk.
+    NeighbourhoodShrunk {
+        /// The [`Scout`] of the [`FlowerPatch`](crate::bees::FlowerPatch).
+        scout: Scout<Hive>,
+        /// The new "radius" of the neighbourhood.
+        neighbourhood: usize,
+    },
+    /// A cycle of the algorithm finished.
+    IterationFinished {
+        /// How many cycles have finished, including this one.
+        iteration: usize,
+        /// The [`Scout`] with the current best [`Colony::Flower`].
+        best: Scout<Hive>,
+    },
+}
+
+/// Receives the [`Event`]s of a run of the bees algorithm.
+///
+/// Besides [`StdoutObserver`], [`Observer`] is implemented for `()`, which
+/// ignores every [`Event`], and for [`Vec`], which records them. An [`Option`]
+/// of an [`Observer`] is also one, which ignores everything when it is
+/// [`None`], and so is a pair of them, which passes everything to both.
+///
+/// # Example
+///
+/// Here, `Ones` is a [`Colony`] that looks for a [`u64`] with as few ones as
+/// possible, and stops after the given number of cycles.
+///
+/// ```rust
+/// use dandelifeon::bees::Event;
+///
+/// let config: BeesConfig = BeesConfig::new(8, 1, 4, 3, 2, 64, 3)?;
+/// let mut rng: SmallRng = SmallRng::seed_from_u64(16);
+/// let mut events: Vec<Event<Ones>> = Vec::new();
+/// let best: Scout<Ones> = Ones(20).bees(&config, &mut rng, &mut events);
+///
+/// let iterations: usize = events
+///     .iter()
+///     .filter(|event| matches!(event, Event::IterationFinished { .. }))
+///     .count();
+/// assert_eq!(iterations, 20);
+///
+/// let bests: Vec<u32> = events
+///     .iter()
+///     .filter_map(|event| match *event {
+///         Event::NewBest(scout) => Some(scout.fitness()),
+///         _ => None,
+///     })
+///     .collect();
+/// assert!(
+///     bests.is_sorted_by(|old, new| new < old),
+///     "bests only improve"
+/// );
+/// let Some(&Event::IterationFinished {
+///     iteration,
+///     best: last,
+/// }) = events.last()
+/// else {
+///     panic!("every cycle should end with Event::IterationFinished");
+/// };
+/// assert_eq!((iteration, last), (20, best));
+#[cfg_attr(doc, doc = include_str!("ones.md"))]
+pub trait Observer<Hive: Colony>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    /// Receives a single [`Event`].
+    fn observe(&mut self, event: Event<Hive>);
+
+    /// Receives the [`Checkpoint`] of the search at the end of every cycle,
+    /// just after its [`Event::IterationFinished`]. By default, this does
+    /// nothing with it.
+    fn checkpoint(&mut self, _checkpoint: &Checkpoint<Hive>) {}
+}
+
+impl<Hive: Colony> Observer<Hive> for ()
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    fn observe(&mut self, _event: Event<Hive>) {}
+}
+
+impl<Hive: Colony, O: Observer<Hive>> Observer<Hive> for Option<O>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    fn observe(&mut self, event: Event<Hive>) {
+        if let Some(ref mut observer) = *self {
+            observer.observe(event);
+        }
+    }
+
+    fn checkpoint(&mut self, checkpoint: &Checkpoint<Hive>) {
+        if let Some(ref mut observer) = *self {
+            observer.checkpoint(checkpoint);
+        }
+    }
+}
+
+impl<Hive, A, B> Observer<Hive> for (A, B)
+where
+    Hive: Colony,
+    A: Observer<Hive>,
+    B: Observer<Hive>,
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    fn observe(&mut self, event: Event<Hive>) {
+        self.0.observe(event);
+        self.1.observe(event);
+    }
+
+    fn checkpoint(&mut self, checkpoint: &Checkpoint<Hive>) {
+        self.0.checkpoint(checkpoint);
+        self.1.checkpoint(checkpoint);
+    }
+}
+
+impl<Hive: Colony> Observer<Hive> for Vec<Event<Hive>>
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    fn observe(&mut self, event: Event<Hive>) {
+        self.push(event);
+    }
+}
+
+/// An [`Observer`] that prints every [`Event::NewBest`] to stdout, and ignores
+/// everything else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StdoutObserver;
+
+impl<Hive: Colony> Observer<Hive> for StdoutObserver
+where
+    StandardUniform: Distribution<Hive::Flower>,
+{
+    fn observe(&mut self, event: Event<Hive>) {
+        if let Event::NewBest(scout) = event {
+            println!(
+                "New best:\n\tFitness:{}\n\tSolution:\n{}",
+                scout.fitness(),
+                scout.solution()
+            );
+        }
+    }
+}
@@ -0,0 +1,449 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Ways of judging a [`PetriDish`] by the [`GameReport`] of its game.
+//!
+//! A [`Hive`] searches for whatever its [`Fitness`] says is best, which by
+//! default is the highest mana, then the lowest cost, then the fewest steps, as
+//! a [`Score`]. The others here trade mana off against what it took to get it.
+//!
+//! [`PetriDish`]: crate::simulation::PetriDish
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+
+#[cfg(doc)]
+use crate::Hive;
+use crate::Score;
+use crate::simulation::GameReport;
+
+/// A measurement of fitness for a simulated Dandelifeon game, where smaller
+/// is better. This is the [`Colony::Nectar`] of a [`Hive`] that uses it.
+///
+/// # Example
+///
+/// A weighted sum, where every cell is worth giving up 500 mana for and every
+/// block is worth giving up 10 mana for:
+///
+/// ```rust
+/// use core::fmt::{self, Display, Formatter};
+///
+/// use dandelifeon::Hive;
+/// use dandelifeon::bees::{Colony, MaxIterations, Scout};
+/// use dandelifeon::fitness::Fitness;
+/// use dandelifeon::simulation::GameReport;
+/// use rand::SeedableRng;
+/// use rand::rngs::SmallRng;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Weighted(i32);
+///
+/// impl Display for Weighted {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         write!(f, "({} weighted mana)", -self.0)
+///     }
+/// }
+///
+/// impl Fitness for Weighted {
+///     fn from_report(report: GameReport) -> Self {
+///         let (cells, blocks): (u16, u16) = report.initial_cost();
+///         Self(
+///             500 * i32::from(cells) + 10 * i32::from(blocks)
+///                 - i32::from(report.mana()),
+///         )
+///     }
+/// }
+///
+/// type Search = Hive<MaxIterations, Weighted>;
+///
+/// let mut hive: Search = Hive::new(MaxIterations::new(2));
+/// let mut rng: SmallRng = SmallRng::seed_from_u64(22);
+/// let best: Scout<Search> = hive.bees(&Search::CONFIG, &mut rng, &mut ());
+/// assert_eq!(best.fitness(), Search::evaluate(&best.solution()));
+/// ```
+///
+/// [`Colony::Nectar`]: crate::bees::Colony::Nectar
+pub trait Fitness: Debug + Copy + Display + Ord {
+    /// Judges a game by its [`GameReport`].
+    fn from_report(report: GameReport) -> Self;
+}
+
+impl<const STEPS_FIRST: bool, C: CostModel> Fitness for Score<STEPS_FIRST, C> {
+    fn from_report(report: GameReport) -> Self {
+        Self::from(report)
+    }
+}
+
+/// Converts the [`Cell::Living`] and [`Cell::Blocked`] on an initial board
+/// into the mana it takes to place them, so that a [`Score`] can weigh its
+/// cost against the mana it generates.
+///
+/// # Example
+///
+/// Cellular blocks that take 1000 mana each to craft, placed next to dirt
+/// that costs nothing:
+///
+/// ```rust
+/// use dandelifeon::Score;
+/// use dandelifeon::fitness::{CostModel, Linear};
+/// use dandelifeon::simulation::{GameReport, PetriDish};
+///
+/// type Crafted = Linear<1000, 0>;
+///
+/// assert_eq!(Crafted::cost(6, 7), 6000);
+///
+/// let record: Score<false, Crafted> =
+///     Score::from(GameReport::new(&PetriDish::OPTIMAL_100_ROUND));
+/// assert_eq!(record.material_cost(), 6000);
+/// assert_eq!(record.profit(), 30_000);
+/// ```
+///
+/// [`Cell::Living`]: crate::simulation::Cell::Living
+/// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
+pub trait CostModel: Debug + Copy + Eq {
+    /// Gets the mana it takes to place `cells` [`Cell::Living`] and `blocks`
+    /// [`Cell::Blocked`].
+    ///
+    /// [`Cell::Living`]: crate::simulation::Cell::Living
+    /// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
+    fn cost(cells: u16, blocks: u16) -> u32;
+}
+
+/// A [`CostModel`] where everything is free, so that a [`Score`] only weighs
+/// its cost when breaking ties. This is the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Free;
+
+impl CostModel for Free {
+    fn cost(_cells: u16, _blocks: u16) -> u32 {
+        0
+    }
+}
+
+/// A [`CostModel`] where every [`Cell::Living`] costs `CELL` mana and every
+/// [`Cell::Blocked`] costs `BLOCK` mana.
+///
+/// [`Cell::Living`]: crate::simulation::Cell::Living
+/// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Linear<const CELL: u32, const BLOCK: u32>;
+
+impl<const CELL: u32, const BLOCK: u32> CostModel for Linear<CELL, BLOCK> {
+    fn cost(cells: u16, blocks: u16) -> u32 {
+        CELL.saturating_mul(u32::from(cells))
+            .saturating_add(BLOCK.saturating_mul(u32::from(blocks)))
+    }
+}
+
+/// A [`Fitness`] favouring the most mana for each [`Cell::Living`] on the
+/// initial board. Ties go to the most mana, and then to the fewest
+/// [`Cell::Blocked`].
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::fitness::{Fitness, ManaPerCell};
+/// use dandelifeon::simulation::{GameReport, PetriDish};
+///
+/// let record: ManaPerCell = ManaPerCell::from_report(GameReport::new(
+///     &PetriDish::OPTIMAL_100_ROUND,
+/// ));
+/// let empty: ManaPerCell =
+///     ManaPerCell::from_report(GameReport::new(&PetriDish::new()));
+///
+/// assert_eq!(
+///     record.to_string(),
+///     "(6000.00 mana per cell, 36000 mana from 6 cells and 7 blocks)"
+/// );
+/// assert!(record < empty);
+/// ```
+///
+/// [`Cell::Living`]: crate::simulation::Cell::Living
+/// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ManaPerCell {
+    /// The mana generated by the game.
+    mana: u16,
+    /// The initial cell cost of the game.
+    cell_cost: u16,
+    /// The initial "blocker" cost of the game.
+    block_cost: u16,
+}
+
+impl ManaPerCell {
+    /// Gets the mana generated by the game.
+    pub const fn mana(self) -> u16 {
+        self.mana
+    }
+
+    /// Gets the initial cell cost of the game.
+    pub const fn cell_cost(self) -> u16 {
+        self.cell_cost
+    }
+
+    /// Gets the initial "blocker" cost of the game.
+    pub const fn block_cost(self) -> u16 {
+        self.block_cost
+    }
+}
+
+impl Display for ManaPerCell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({:.2} mana per cell, {} mana from {} cells and {} blocks)",
+            rate(self.mana, self.cell_cost),
+            self.mana,
+            self.cell_cost,
+            self.block_cost
+        )
+    }
+}
+
+impl Fitness for ManaPerCell {
+    fn from_report(report: GameReport) -> Self {
+        let (cell_cost, block_cost): (u16, u16) = report.initial_cost();
+        Self {
+            mana: report.mana(),
+            cell_cost,
+            block_cost,
+        }
+    }
+}
+
+impl PartialOrd for ManaPerCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ManaPerCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_rates(
+            (self.mana, self.cell_cost),
+            (other.mana, other.cell_cost),
+        )
+        .then_with(|| self.block_cost.cmp(&other.block_cost))
+    }
+}
+
+/// A [`Fitness`] favouring the most mana for each step the game takes. Ties go
+/// to the most mana, and then to the lowest cost, as for a [`Score`].
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::fitness::{Fitness, ManaPerStep};
+/// use dandelifeon::simulation::{GameReport, PetriDish};
+///
+/// let record: ManaPerStep = ManaPerStep::from_report(GameReport::new(
+///     &PetriDish::OPTIMAL_100_ROUND,
+/// ));
+/// let empty: ManaPerStep =
+///     ManaPerStep::from_report(GameReport::new(&PetriDish::new()));
+///
+/// assert_eq!(record.steps(), 100);
+/// assert!(record < empty);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ManaPerStep {
+    /// The mana generated by the game.
+    mana: u16,
+    /// The number of steps the game took.
+    steps: u8,
+    /// The initial cell cost of the game.
+    cell_cost: u16,
+    /// The initial "blocker" cost of the game.
+    block_cost: u16,
+}
+
+impl ManaPerStep {
+    /// Gets the mana generated by the game.
+    pub const fn mana(self) -> u16 {
+        self.mana
+    }
+
+    /// Gets the number of steps the game took.
+    pub const fn steps(self) -> u8 {
+        self.steps
+    }
+
+    /// Gets the initial cell cost of the game.
+    pub const fn cell_cost(self) -> u16 {
+        self.cell_cost
+    }
+
+    /// Gets the initial "blocker" cost of the game.
+    pub const fn block_cost(self) -> u16 {
+        self.block_cost
+    }
+}
+
+impl Display for ManaPerStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({:.2} mana per step, {} mana in {} steps, cost {} cells and {} \
+             blocks)",
+            rate(self.mana, u16::from(self.steps)),
+            self.mana,
+            self.steps,
+            self.cell_cost,
+            self.block_cost
+        )
+    }
+}
+
+impl Fitness for ManaPerStep {
+    fn from_report(report: GameReport) -> Self {
+        let (cell_cost, block_cost): (u16, u16) = report.initial_cost();
+        Self {
+            mana: report.mana(),
+            steps: report.steps(),
+            cell_cost,
+            block_cost,
+        }
+    }
+}
+
+impl PartialOrd for ManaPerStep {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ManaPerStep {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_rates(
+            (self.mana, u16::from(self.steps)),
+            (other.mana, u16::from(other.steps)),
+        )
+        .then_with(|| self.cell_cost.cmp(&other.cell_cost))
+        .then_with(|| self.block_cost.cmp(&other.block_cost))
+    }
+}
+
+/// A [`Fitness`] favouring the lowest cost among games that generate at least
+/// `MANA` mana.
+///
+/// Games that fall short are ranked by how far short they fall, and ties go to
+/// the fewest [`Cell::Living`], then the fewest [`Cell::Blocked`], then the
+/// most mana.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::fitness::{AtLeast, Fitness};
+/// use dandelifeon::simulation::{GameReport, PetriDish};
+///
+/// let record: AtLeast<30_000> =
+///     AtLeast::from_report(GameReport::new(&PetriDish::OPTIMAL_100_ROUND));
+/// let empty: AtLeast<30_000> =
+///     AtLeast::from_report(GameReport::new(&PetriDish::new()));
+///
+/// assert_eq!(record.shortfall(), 0);
+/// assert_eq!(empty.shortfall(), 30_000);
+/// assert!(record < empty);
+/// ```
+///
+/// [`Cell::Living`]: crate::simulation::Cell::Living
+/// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AtLeast<const MANA: u16> {
+    /// The mana generated by the game.
+    mana: u16,
+    /// The initial cell cost of the game.
+    cell_cost: u16,
+    /// The initial "blocker" cost of the game.
+    block_cost: u16,
+}
+
+impl<const MANA: u16> AtLeast<MANA> {
+    /// Gets the mana generated by the game.
+    pub const fn mana(self) -> u16 {
+        self.mana
+    }
+
+    /// Gets how much less mana than `MANA` the game generated, which is zero
+    /// once the target is met.
+    pub const fn shortfall(self) -> u16 {
+        MANA.saturating_sub(self.mana)
+    }
+
+    /// Gets the initial cell cost of the game.
+    pub const fn cell_cost(self) -> u16 {
+        self.cell_cost
+    }
+
+    /// Gets the initial "blocker" cost of the game.
+    pub const fn block_cost(self) -> u16 {
+        self.block_cost
+    }
+}
+
+impl<const MANA: u16> Display for AtLeast<MANA> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({} of {} mana, cost {} cells and {} blocks)",
+            self.mana, MANA, self.cell_cost, self.block_cost
+        )
+    }
+}
+
+impl<const MANA: u16> Fitness for AtLeast<MANA> {
+    fn from_report(report: GameReport) -> Self {
+        let (cell_cost, block_cost): (u16, u16) = report.initial_cost();
+        Self {
+            mana: report.mana(),
+            cell_cost,
+            block_cost,
+        }
+    }
+}
+
+impl<const MANA: u16> PartialOrd for AtLeast<MANA> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const MANA: u16> Ord for AtLeast<MANA> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.shortfall()
+            .cmp(&other.shortfall())
+            .then_with(|| self.cell_cost.cmp(&other.cell_cost))
+            .then_with(|| self.block_cost.cmp(&other.block_cost))
+            .then_with(|| self.mana.cmp(&other.mana).reverse())
+    }
+}
+
+/// Compares two amounts of mana by how much of it there is for each of
+/// something, given as `(mana, per)`, where more is better and so comes first.
+///
+/// Ties go to the most mana, and then to the smallest `per`. Nothing is
+/// treated as a rate of zero, rather than dividing by it.
+fn compare_rates(first: (u16, u16), second: (u16, u16)) -> Ordering {
+    let scale = |(mana, per): (u16, u16), by: u16| -> u32 {
+        if per == 0 {
+            0
+        } else {
+            u32::from(mana).saturating_mul(u32::from(by.max(1)))
+        }
+    };
+    scale(second, first.1)
+        .cmp(&scale(first, second.1))
+        .then_with(|| first.0.cmp(&second.0).reverse())
+        .then_with(|| first.1.cmp(&second.1))
+}
+
+/// Gets `mana` divided by `per` for display, treating nothing as a rate of
+/// zero.
+fn rate(mana: u16, per: u16) -> f64 {
+    if per == 0 {
+        0.0
+    } else {
+        f64::from(mana) / f64::from(per)
+    }
+}
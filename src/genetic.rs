@@ -0,0 +1,422 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A genetic algorithm for the Dandelifeon, to compare against the bees
+//! algorithm. It is based on pseudocode like so:
+//!
+//! ```text
+//! Pseudocode for the genetic algorithm:
+//!    1 for i = 1, ..., ps
+//!        i individual[i] = Initialise_individual()
+//!    2 do until stopping_condition = TRUE
+//!        i   Sort(individual)
+//!        ii  for i = ne + 1, ..., ps
+//!              1 first = Tournament(individual)
+//!              2 second = Tournament(individual)
+//!              3 individual[i] = Mutation(Crossover(first, second))
+//! ```
+//!
+//! It runs on a [`Hive`], judging every [`PetriDish`] with [`Hive::evaluate`]
+//! and stopping when its [`StoppingCondition`] says so, exactly as
+//! [`Colony::bees`] does. A [`MaxEvaluations`] makes for a fair comparison,
+//! since the two algorithms spend their evaluations very differently.
+//!
+//! [`MaxEvaluations`]: crate::bees::MaxEvaluations
+
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter;
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::Hive;
+use crate::bees::{
+    Colony, Event, Observer, Progress, Scout, StoppingCondition,
+};
+use crate::fitness::Fitness;
+use crate::simulation::PetriDish;
+
+/// How two parent [`PetriDish`]es are combined into a child.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Crossover {
+    /// Every cell comes from either parent, at random.
+    Uniform,
+    /// The cells in a random rectangle come from the second parent, and the
+    /// rest from the first, keeping shapes within each parent together.
+    Block,
+}
+
+impl Crossover {
+    /// Combines `first` and `second` into a child.
+    fn cross<R: Rng>(
+        self,
+        first: PetriDish,
+        second: PetriDish,
+        rng: &mut R,
+    ) -> PetriDish {
+        let mask: [u64; 25] = match self {
+            Self::Uniform => rng.random(),
+            Self::Block => {
+                let (left, right): (u8, u8) = span(rng);
+                let (bottom, top): (u8, u8) = span(rng);
+                let columns: u64 =
+                    (left..right).fold(0, |columns: u64, x: u8| {
+                        columns
+                            | 1_u64
+                                .checked_shl(u32::from(x).saturating_mul(2))
+                                .unwrap_or(0)
+                    });
+                let mut mask: [u64; 25] = [0; 25];
+                for row in mask
+                    .iter_mut()
+                    .take(usize::from(top))
+                    .skip(usize::from(bottom))
+                {
+                    *row = columns;
+                }
+                mask
+            }
+        };
+        first.splice(second, mask)
+    }
+}
+
+/// The parameters of a run of the genetic algorithm.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::genetic::{ConfigError, Crossover, GeneticConfig};
+///
+/// let config: GeneticConfig =
+///     GeneticConfig::new(60, 2, 3, Crossover::Block, 4)?;
+/// assert_eq!(config, GeneticConfig::DEFAULT);
+/// assert_eq!(config.children(), 58);
+///
+/// assert_eq!(
+///     GeneticConfig::new(60, 60, 3, Crossover::Block, 4),
+///     Err(ConfigError::TooManyElites {
+///         elites: 60,
+///         population: 60
+///     })
+/// );
+/// # Ok::<(), ConfigError>(())
+/// ```
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "it sits next to BeesConfig wherever the two are compared"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GeneticConfig {
+    /// The number of individuals in every generation.
+    population: usize,
+    /// The number of best individuals carried over to the next generation
+    /// unchanged.
+    elites: usize,
+    /// The number of individuals that compete to be picked as a parent.
+    tournament_size: usize,
+    /// How parents are combined.
+    crossover: Crossover,
+    /// The average number of cells of a child that are randomised.
+    mutations: u32,
+}
+
+impl GeneticConfig {
+    /// The [`GeneticConfig`] used when there is no reason to pick another,
+    /// with as many individuals as a [`Hive`] has scouts.
+    pub const DEFAULT: Self = match Self::new(60, 2, 3, Crossover::Block, 4) {
+        Ok(config) => config,
+        Err(_) => panic!("GeneticConfig::DEFAULT must be valid"),
+    };
+
+    /// Creates a new [`GeneticConfig`], checking that the parameters make
+    /// sense together.
+    ///
+    /// Every generation, the `elites` best of the `population` are kept, and
+    /// the rest are replaced by children. Each parent of a child is the best
+    /// of `tournament_size` individuals picked at random, and the parents are
+    /// combined by `crossover`. Each of the 624 cells of the child, other than
+    /// the [`Cell::Dandelifeon`], is then randomised with a chance of
+    /// `mutations` in 624.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::TooManyElites`] if `elites` is not smaller than
+    /// `population`.
+    ///
+    /// Returns [`ConfigError::EmptyTournament`] if `tournament_size` is zero.
+    ///
+    /// Returns [`ConfigError::TooManyMutations`] if `mutations` is larger
+    /// than 624.
+    ///
+    /// [`Cell::Dandelifeon`]: crate::simulation::Cell::Dandelifeon
+    pub const fn new(
+        population: usize,
+        elites: usize,
+        tournament_size: usize,
+        crossover: Crossover,
+        mutations: u32,
+    ) -> Result<Self, ConfigError> {
+        if elites >= population {
+            return Err(ConfigError::TooManyElites { elites, population });
+        }
+        if tournament_size == 0 {
+            return Err(ConfigError::EmptyTournament);
+        }
+        if mutations > 624 {
+            return Err(ConfigError::TooManyMutations { mutations });
+        }
+        Ok(Self {
+            population,
+            elites,
+            tournament_size,
+            crossover,
+            mutations,
+        })
+    }
+
+    /// Gets the number of individuals in every generation.
+    pub const fn population(&self) -> usize {
+        self.population
+    }
+
+    /// Gets the number of best individuals carried over to the next
+    /// generation unchanged.
+    pub const fn elites(&self) -> usize {
+        self.elites
+    }
+
+    /// Gets the number of individuals that compete to be picked as a parent.
+    pub const fn tournament_size(&self) -> usize {
+        self.tournament_size
+    }
+
+    /// Gets how parents are combined.
+    pub const fn crossover(&self) -> Crossover {
+        self.crossover
+    }
+
+    /// Gets the average number of cells of a child that are randomised.
+    pub const fn mutations(&self) -> u32 {
+        self.mutations
+    }
+
+    /// Gets the number of children made, and so evaluated, every generation.
+    pub const fn children(&self) -> usize {
+        self.population.saturating_sub(self.elites)
+    }
+}
+
+/// An error encountered while creating a [`GeneticConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConfigError {
+    /// There must be fewer elites than individuals, so that every generation
+    /// makes at least one child.
+    TooManyElites {
+        /// The offending number of elites.
+        elites: usize,
+        /// The number of individuals.
+        population: usize,
+    },
+    /// At least one individual must compete in every tournament.
+    EmptyTournament,
+    /// There are only 624 cells that can be mutated.
+    TooManyMutations {
+        /// The offending average number of mutations.
+        mutations: u32,
+    },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::TooManyElites { elites, population } => write!(
+                f,
+                "there must be fewer elites than individuals, but there are \
+                {elites} elites and {population} individuals"
+            ),
+            Self::EmptyTournament => {
+                write!(
+                    f,
+                    "at least one individual must compete in a tournament"
+                )
+            }
+            Self::TooManyMutations { mutations } => write!(
+                f,
+                "there are only 624 cells that can be mutated, but {mutations} \
+                mutations were asked for"
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+impl<S, F> Hive<S, F>
+where
+    S: StoppingCondition<F> + Debug + Copy,
+    F: Fitness,
+{
+    /// The genetic algorithm in full, with the parameters given by `config`.
+    /// Returns the [`Scout`] with the best [`PetriDish`] found.
+    ///
+    /// Each generation is one cycle of the [`Progress`] given to the
+    /// [`StoppingCondition`], and ends with an [`Event::IterationFinished`].
+    /// Every individual is passed to `observer` as an [`Event::Evaluated`],
+    /// and every new best as an [`Event::NewBest`], just as in
+    /// [`Colony::bees`]. There are no flower patches to abandon or shrink, or
+    /// [`Checkpoint`](crate::bees::Checkpoint)s to take, so `observer` is
+    /// never given anything else.
+    ///
+    /// # Example
+    ///
+    /// Pitting the two algorithms against each other with the same budget:
+    ///
+    /// ```rust
+    /// use dandelifeon::Hive;
+    /// use dandelifeon::bees::{Colony, Event, MaxEvaluations, Scout};
+    /// use dandelifeon::genetic::GeneticConfig;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// type Search = Hive<MaxEvaluations>;
+    ///
+    /// let mut rng: SmallRng = SmallRng::seed_from_u64(25);
+    /// let mut hive: Search = Hive::new(MaxEvaluations::new(600));
+    /// let mut events: Vec<Event<Search>> = Vec::new();
+    /// let genetic: Scout<Search> =
+    ///     hive.genetic(&GeneticConfig::DEFAULT, &mut rng, &mut events);
+    ///
+    /// let mut hive: Search = Hive::new(MaxEvaluations::new(600));
+    /// let bees: Scout<Search> = hive.bees(&Search::CONFIG, &mut rng, &mut ());
+    /// println!("genetic: {}, bees: {}", genetic.fitness(), bees.fitness());
+    ///
+    /// // 60 to start with, and then 58 children in each generation.
+    /// let generations: usize = events
+    ///     .iter()
+    ///     .filter(|event| matches!(event, Event::IterationFinished { .. }))
+    ///     .count();
+    /// assert_eq!(generations, 10);
+    /// assert_eq!(genetic.fitness(), Search::evaluate(&genetic.solution()));
+    /// ```
+    pub fn genetic<R: Rng, O: Observer<Self>>(
+        &mut self,
+        config: &GeneticConfig,
+        rng: &mut R,
+        observer: &mut O,
+    ) -> Scout<Self> {
+        let solutions: Vec<PetriDish> = iter::repeat_with(|| rng.random())
+            .take(config.population)
+            .collect();
+        let mut population: Vec<Scout<Self>> =
+            Scout::with_solutions(solutions).collect();
+        for &scout in &population {
+            observer.observe(Event::Evaluated(scout));
+        }
+        population.sort_by_key(|scout: &Scout<Self>| scout.fitness());
+
+        #[expect(
+            clippy::indexing_slicing,
+            reason = "a valid GeneticConfig always has more individuals than
+            elites, so there is at least one"
+        )]
+        let mut best: Scout<Self> = population[0];
+        let mut progress: Progress<F> =
+            Progress::new(config.population, best.fitness());
+        let started: Instant = Instant::now();
+        while !self.stopping_condition(&progress) {
+            let children: Vec<PetriDish> = iter::repeat_with(|| {
+                let first: PetriDish =
+                    tournament(&population, config.tournament_size, rng);
+                let second: PetriDish =
+                    tournament(&population, config.tournament_size, rng);
+                let child: PetriDish =
+                    config.crossover.cross(first, second, rng);
+                mutate(child, config.mutations, rng)
+            })
+            .take(config.children())
+            .collect();
+
+            population.truncate(config.elites);
+            for scout in Scout::with_solutions(children) {
+                observer.observe(Event::Evaluated(scout));
+                population.push(scout);
+            }
+            population.sort_by_key(|scout: &Scout<Self>| scout.fitness());
+
+            #[expect(
+                clippy::indexing_slicing,
+                reason = "a valid GeneticConfig always makes at least one
+                child"
+            )]
+            let scout: Scout<Self> = population[0];
+            if scout.fitness() < best.fitness() {
+                best = scout;
+                observer.observe(Event::NewBest(best));
+            }
+            progress.finish_iteration(
+                config.children(),
+                best.fitness(),
+                started.elapsed(),
+            );
+            observer.observe(Event::IterationFinished {
+                iteration: progress.iterations(),
+                best,
+            });
+        }
+        best
+    }
+}
+
+/// Picks `size` of the `population` at random, with replacement, and returns
+/// the [`PetriDish`] of the best of them. Assumes `population` is sorted by
+/// fitness and not empty.
+fn tournament<H, R>(
+    population: &[Scout<H>],
+    size: usize,
+    rng: &mut R,
+) -> PetriDish
+where
+    H: Colony<Flower = PetriDish>,
+    R: Rng,
+{
+    let winner: usize =
+        iter::repeat_with(|| rng.random_range(0..population.len()))
+            .take(size)
+            .min()
+            .unwrap_or(0);
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "winner is always an index of population, which is never
+        empty"
+    )]
+    population[winner].solution()
+}
+
+/// Randomises each cell of `dish` other than the [`Cell::Dandelifeon`] with a
+/// chance of `mutations` in 624.
+///
+/// [`Cell::Dandelifeon`]: crate::simulation::Cell::Dandelifeon
+fn mutate<R: Rng>(
+    mut dish: PetriDish,
+    mutations: u32,
+    rng: &mut R,
+) -> PetriDish {
+    for (x, y) in PetriDish::NONCENTER_COORDS {
+        if rng.random_ratio(mutations, 624) {
+            let value: u8 = rng.random_range(0..3);
+            dish.write(x, y, value);
+        }
+    }
+    dish
+}
+
+/// Picks a random range of rows or columns, as `(start, end)`, which may be
+/// empty.
+fn span<R: Rng>(rng: &mut R) -> (u8, u8) {
+    let first: u8 = rng.random_range(0..=25);
+    let second: u8 = rng.random_range(0..=25);
+    (first.min(second), first.max(second))
+}
@@ -3,88 +3,261 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 //! A library for searching for solutions to the Dandelifeon using the bees
-//! algorithm.
+//! algorithm, or a genetic algorithm to compare it against.
 
 use core::cmp::Ordering;
-use core::fmt::{Display, Formatter, Result};
+use core::fmt::{Debug, Display, Formatter, Result};
+use core::marker::PhantomData;
 
 use rand::Rng;
 use rand::seq::SliceRandom as _;
 
-use crate::bees::{Colony, Scout};
-use crate::simulation::PetriDish;
+use crate::bees::{
+    BeesConfig, Colony, Never, Progress, Scout, StoppingCondition,
+};
+use crate::fitness::{CostModel, Fitness, Free};
+use crate::simulation::{GameReport, PetriDish};
 
 pub mod bees;
+pub mod fitness;
+pub mod genetic;
+pub mod minecraft;
+pub mod pareto;
 pub mod simulation;
 
-/// A measurement of fitness for a simulated Dandelifeon game.
+/// A measurement of fitness for a simulated Dandelifeon game. This is the
+/// default [`Fitness`] of a [`Hive`].
+///
+/// It favours the most mana profit, then the fewest [`Cell::Living`], then
+/// the fewest [`Cell::Blocked`], then the fewest steps. When `STEPS_FIRST` is
+/// `true`, the fewest steps are favoured straight after the most profit
+/// instead, ahead of the lowest cost. The profit is the mana generated, less
+/// the cost of the initial board according to the [`CostModel`] `C`, which by
+/// default is [`Free`].
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::Score;
+/// use dandelifeon::fitness::Linear;
+/// use dandelifeon::simulation::{GameReport, PetriDish};
+///
+/// let record: Score =
+///     Score::from(GameReport::new(&PetriDish::OPTIMAL_100_ROUND));
+///
+/// assert_eq!(record, Score::new(36_000, 6, 7, 100));
+/// assert_eq!(record.steps(), 100);
+/// assert_eq!(record.profit(), 36_000);
+/// assert!(record < Score::new(36_000, 6, 7, 101));
+/// assert!(record < Score::new(36_000, 6, 8, 50));
+/// assert!(record > Score::new(36_001, 100, 100, 255));
+///
+/// let quick: Score<true> = Score::new(36_000, 6, 7, 100);
+/// assert!(quick > Score::new(36_000, 6, 8, 50));
+///
+/// let crafted: Score<false, Linear<100, 1>> = Score::new(36_000, 6, 7, 100);
+/// assert_eq!(crafted.profit(), 35_393);
+/// assert_eq!(
+///     crafted.to_string(),
+///     "(36000 mana, cost 6 cells and 7 blocks worth 607 mana, 35393 mana \
+///      profit, 100 steps)"
+/// );
+/// assert!(crafted < Score::new(36_001, 100, 100, 255));
+/// assert!(crafted > Score::new(36_001, 6, 0, 255));
+/// ```
+///
+/// [`Cell::Living`]: crate::simulation::Cell::Living
+/// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Score {
+pub struct Score<const STEPS_FIRST: bool = false, C = Free> {
     /// The mana generated by the game.
     mana: u16,
     /// The initial cell cost of the game.
     cell_cost: u16,
     /// The initial "blocker" cost of the game.
     block_cost: u16,
+    /// The number of steps the game took.
+    steps: u8,
+    /// How the initial cost is converted into mana.
+    cost_model: PhantomData<C>,
 }
 
-impl Display for Score {
+impl<const STEPS_FIRST: bool, C: CostModel> Score<STEPS_FIRST, C> {
+    /// Creates a new [`Score`] for a game that generated `mana` mana in
+    /// `steps` steps, from a board with `cell_cost` [`Cell::Living`] and
+    /// `block_cost` [`Cell::Blocked`].
+    ///
+    /// [`Cell::Living`]: crate::simulation::Cell::Living
+    /// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
+    pub const fn new(
+        mana: u16,
+        cell_cost: u16,
+        block_cost: u16,
+        steps: u8,
+    ) -> Self {
+        Self {
+            mana,
+            cell_cost,
+            block_cost,
+            steps,
+            cost_model: PhantomData,
+        }
+    }
+
+    /// Gets the mana generated by the game.
+    pub const fn mana(self) -> u16 {
+        self.mana
+    }
+
+    /// Gets the initial cell cost of the game.
+    pub const fn cell_cost(self) -> u16 {
+        self.cell_cost
+    }
+
+    /// Gets the initial "blocker" cost of the game.
+    pub const fn block_cost(self) -> u16 {
+        self.block_cost
+    }
+
+    /// Gets the number of steps the game took.
+    pub const fn steps(self) -> u8 {
+        self.steps
+    }
+
+    /// Gets the mana it takes to set up the initial board, according to the
+    /// [`CostModel`] `C`.
+    pub fn material_cost(self) -> u32 {
+        C::cost(self.cell_cost, self.block_cost)
+    }
+
+    /// Gets the mana generated by the game, less the
+    /// [`Score::material_cost`]. This is negative if the game does not make
+    /// back what it cost.
+    pub fn profit(self) -> i64 {
+        i64::from(self.mana).saturating_sub(i64::from(self.material_cost()))
+    }
+}
+
+impl<const STEPS_FIRST: bool, C: CostModel> Display for Score<STEPS_FIRST, C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(
             f,
-            "({} mana, cost {} cells and {} blocks)",
+            "({} mana, cost {} cells and {} blocks",
             self.mana, self.cell_cost, self.block_cost
-        )
+        )?;
+        if C::cost(1, 0) != 0 || C::cost(0, 1) != 0 {
+            write!(
+                f,
+                " worth {} mana, {} mana profit",
+                self.material_cost(),
+                self.profit()
+            )?;
+        }
+        write!(f, ", {} steps)", self.steps)
+    }
+}
+
+impl<const STEPS_FIRST: bool, C> From<GameReport> for Score<STEPS_FIRST, C> {
+    fn from(value: GameReport) -> Self {
+        let (cell_cost, block_cost): (u16, u16) = value.initial_cost();
+        Self {
+            mana: value.mana(),
+            cell_cost,
+            block_cost,
+            steps: value.steps(),
+            cost_model: PhantomData,
+        }
     }
 }
 
-impl PartialOrd for Score {
+impl<const STEPS_FIRST: bool, C: CostModel> PartialOrd
+    for Score<STEPS_FIRST, C>
+{
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for Score {
+impl<const STEPS_FIRST: bool, C: CostModel> Ord for Score<STEPS_FIRST, C> {
     fn cmp(&self, other: &Self) -> Ordering {
         if let ord @ (Ordering::Less | Ordering::Greater) =
-            self.mana.cmp(&other.mana)
+            self.profit().cmp(&other.profit())
         {
             return ord.reverse();
         }
+        let steps: Ordering = self.steps.cmp(&other.steps);
+        if STEPS_FIRST && steps.is_ne() {
+            return steps;
+        }
         if let ord @ (Ordering::Less | Ordering::Greater) =
             self.cell_cost.cmp(&other.cell_cost)
         {
             return ord;
         }
-        self.block_cost.cmp(&other.block_cost)
+        self.block_cost.cmp(&other.block_cost).then(steps)
     }
 }
 
 /// A [`Colony`] for running the bees algorithm to find solutions to the
-/// Dandelifeon.
+/// Dandelifeon. The search stops once the [`StoppingCondition`] it was created
+/// with says so, which by default is [`Never`].
+///
+/// What counts as a better solution is up to its [`Fitness`], which by
+/// default is a [`Score`].
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::Hive;
+/// use dandelifeon::bees::{Colony, MaxIterations, NoImprovement, Or, Scout};
+/// use rand::SeedableRng;
+/// use rand::rngs::SmallRng;
+///
+/// type Limits = Or<MaxIterations, NoImprovement>;
+///
+/// let limits: Limits = Or::new(MaxIterations::new(3), NoImprovement::new(10));
+/// let mut hive: Hive<Limits> = Hive::new(limits);
+/// let mut rng: SmallRng = SmallRng::seed_from_u64(17);
+///
+/// // Returns after at most 3 cycles.
+/// let best: Scout<Hive<Limits>> =
+///     hive.bees(&Hive::<Limits>::CONFIG, &mut rng, &mut ());
+/// println!("{}", best.fitness());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Hive {
-    /// The iteration count.
-    iters: usize,
+pub struct Hive<S = Never, F = Score> {
+    /// Decides when the search should stop.
+    stopping_condition: S,
+    /// What the search is looking for.
+    fitness: PhantomData<F>,
 }
 
-impl Hive {
-    /// Creates a new [`Hive`].
-    pub const fn new() -> Self {
-        Self { iters: 0 }
+impl<S, F> Hive<S, F> {
+    /// Creates a new [`Hive`] that searches until `stopping_condition` says to
+    /// stop.
+    pub const fn new(stopping_condition: S) -> Self {
+        Self {
+            stopping_condition,
+            fitness: PhantomData,
+        }
     }
 }
 
-impl Default for Hive {
+impl<S: Default, F> Default for Hive<S, F> {
     fn default() -> Self {
-        Self::new()
+        Self::new(S::default())
     }
 }
 
-impl Colony<60> for Hive {
+impl<S, F> Colony for Hive<S, F>
+where
+    S: StoppingCondition<F> + Debug + Copy,
+    F: Fitness,
+{
     type Flower = PetriDish;
-    type Nectar = Score;
+    type Nectar = F;
 
+    const SCOUTS: usize = 60;
     const ELITE_SITES: usize = 5;
     const BEST_SITES: usize = 55;
     const ELITE_RECRUITS: usize = 10;
@@ -93,28 +266,29 @@ impl Colony<60> for Hive {
     const STAGNATION_LIMIT: usize = 21;
 
     fn evaluate(solution: &Self::Flower) -> Self::Nectar {
-        let mut game: PetriDish = *solution;
-        let mana: u16 = game.play();
-        let (cell_cost, block_cost) = solution.count_living_and_blocked();
+        F::from_report(GameReport::new(solution))
+    }
 
-        Score {
-            mana,
-            cell_cost,
-            block_cost,
-        }
+    fn evaluate_batch(solutions: &[Self::Flower]) -> Vec<Self::Nectar> {
+        let mut games: Vec<PetriDish> = solutions.to_vec();
+        PetriDish::play_many(&mut games)
+            .into_iter()
+            .map(F::from_report)
+            .collect()
     }
 
     fn explore<R: Rng>(
         origin: &Self::Flower,
-        current_best: &Scout<60, Self>,
+        current_best: &Scout<Self>,
         radius: usize,
+        config: &BeesConfig,
         rng: &mut R,
     ) -> Self::Flower {
         if radius == (25 * 25) {
             let new: Self::Flower = rng.random();
             return new;
         }
-        let mut base: Self::Flower = if radius <= Self::MINIMUM_RADIUS {
+        let mut base: Self::Flower = if radius <= config.minimum_radius() {
             current_best.solution()
         } else {
             *origin
@@ -130,8 +304,7 @@ impl Colony<60> for Hive {
         base
     }
 
-    fn stopping_condition(&mut self) -> bool {
-        // Just keep going.
-        false
+    fn stopping_condition(&mut self, progress: &Progress<F>) -> bool {
+        self.stopping_condition.should_stop(progress)
     }
 }
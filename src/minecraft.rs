@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Exporting [`PetriDish`]es to formats that can be placed in-game.
+//!
+//! Every exporter lays the board out on a single horizontal layer. The x
+//! coordinate of a [`Cell`] is kept as-is, while its y coordinate becomes the
+//! z coordinate counted from the top of the [`Display`]
+//! output. Seen from above with north facing up, the result looks exactly like
+//! the text printed by the binary. The [`Cell::Dandelifeon`] ends up at
+//! (12, 0, 12) relative to the origin of the export, and will need a block
+//! that flowers can be planted on underneath it.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+use crate::simulation::{Cell, DishError, PetriDish};
+
+pub mod datapack;
+pub mod function;
+pub mod litematic;
+pub mod nbt;
+pub mod schematic;
+pub mod structure;
+
+/// The data version written to exported files. This is the data version of
+/// Minecraft 1.20.1, the most recent version supported by Botania.
+pub const DATA_VERSION: i32 = 3465;
+
+/// The blocks used to represent each kind of [`Cell`] in-game.
+///
+/// [`Cell::Dead`] is always represented by air. Which block is used for
+/// [`Cell::Blocked`] is a matter of taste, so it can be changed with
+/// [`Palette::with_blocker`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Palette {
+    /// The block used for [`Cell::Living`].
+    cellular: String,
+    /// The block used for [`Cell::Blocked`].
+    blocker: String,
+    /// The block used for the [`Cell::Dandelifeon`].
+    flower: String,
+}
+
+impl Palette {
+    /// The block used for [`Cell::Dead`].
+    pub const AIR: &str = "minecraft:air";
+
+    /// The block used for [`Cell::Living`] by default.
+    pub const CELLULAR_BLOCK: &str = "botania:cellular_block";
+
+    /// The block used for [`Cell::Blocked`] by default.
+    pub const DIRT: &str = "minecraft:dirt";
+
+    /// The block used for the [`Cell::Dandelifeon`] by default.
+    pub const DANDELIFEON: &str = "botania:dandelifeon";
+
+    /// Creates a new [`Palette`] using the default blocks.
+    pub fn new() -> Self {
+        Self {
+            cellular: Self::CELLULAR_BLOCK.to_owned(),
+            blocker: Self::DIRT.to_owned(),
+            flower: Self::DANDELIFEON.to_owned(),
+        }
+    }
+
+    /// Replaces the block used for [`Cell::Blocked`].
+    #[must_use]
+    pub fn with_blocker<S: Into<String>>(mut self, blocker: S) -> Self {
+        self.blocker = blocker.into();
+        self
+    }
+
+    /// Gets the block used for the given [`Cell`].
+    pub fn block(&self, cell: Cell) -> &str {
+        match cell {
+            Cell::Dead => Self::AIR,
+            Cell::Living => &self.cellular,
+            Cell::Blocked => &self.blocker,
+            Cell::Dandelifeon => &self.flower,
+        }
+    }
+
+    /// Gets the [`Cell`] that the given block behaves as. Any kind of air is
+    /// [`Cell::Dead`], and any block that is not the cellular block or the
+    /// flower is [`Cell::Blocked`], just like in-game. Block state properties,
+    /// such as the `[snowy=false]` in `minecraft:dirt[snowy=false]`, are
+    /// ignored.
+    pub fn cell(&self, block: &str) -> Cell {
+        let block: &str = block.split_once('[').map_or(block, |(name, _)| name);
+        match block {
+            "minecraft:air" | "minecraft:cave_air" | "minecraft:void_air" => {
+                Cell::Dead
+            }
+            _ if block == self.cellular => Cell::Living,
+            _ if block == self.flower => Cell::Dandelifeon,
+            _ => Cell::Blocked,
+        }
+    }
+
+    /// All of the blocks in this [`Palette`], in the order of the [`Cell`]
+    /// variants.
+    pub(crate) fn blocks(&self) -> [&str; 4] {
+        [Self::AIR, &self.cellular, &self.blocker, &self.flower]
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An error encountered while reading an exported [`PetriDish`] back in.
+#[derive(Debug)]
+pub enum FormatError {
+    /// The file could not be read as NBT.
+    Nbt(nbt::Error),
+    /// A required field was missing or had the wrong type.
+    MissingField(&'static str),
+    /// The region described by the file is not a single 25 by 25 layer.
+    WrongSize,
+    /// A block could not be placed on the [`PetriDish`].
+    Dish(DishError),
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Nbt(ref error) => write!(f, "invalid NBT: {error}"),
+            Self::MissingField(field) => {
+                write!(f, "missing or invalid field \"{field}\"")
+            }
+            Self::WrongSize => write!(f, "region is not 25 by 1 by 25"),
+            Self::Dish(ref error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for FormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::Nbt(ref error) => Some(error),
+            Self::Dish(ref error) => Some(error),
+            Self::MissingField(_) | Self::WrongSize => None,
+        }
+    }
+}
+
+impl From<nbt::Error> for FormatError {
+    fn from(value: nbt::Error) -> Self {
+        Self::Nbt(value)
+    }
+}
+
+impl From<DishError> for FormatError {
+    fn from(value: DishError) -> Self {
+        Self::Dish(value)
+    }
+}
+
+/// Converts the coordinates of a [`Cell`] on a [`PetriDish`] into the x and z
+/// coordinates of the corresponding block. The conversion is its own inverse.
+pub(crate) const fn to_block(x: u8, y: u8) -> (u8, u8) {
+    (x, 24_u8.saturating_sub(y))
+}
+
+/// Returns the [`Cell`]s of `dish` in the order blocks are usually stored in
+/// files, with x increasing fastest and then z. The index of a [`Cell`] in this
+/// order is `x + z * 25`.
+pub(crate) fn layer(dish: &PetriDish) -> impl Iterator<Item = Cell> {
+    let dish: PetriDish = *dish;
+    (0..25_u8).flat_map(move |z: u8| {
+        (0..25_u8).map(move |x: u8| {
+            let (x, y): (u8, u8) = to_block(x, z);
+            dish.get(x, y).unwrap_or(Cell::Dead)
+        })
+    })
+}
+
+/// Converts an index into a [`layer`] back into block coordinates.
+pub(crate) fn layer_position(index: usize) -> [i32; 3] {
+    let (x, z): (usize, usize) = (index.rem_euclid(25), index.div_euclid(25));
+    [
+        i32::try_from(x).unwrap_or(i32::MAX),
+        0,
+        i32::try_from(z).unwrap_or(i32::MAX),
+    ]
+}
+
+/// Places the [`Cell`] represented by `block` at the given block coordinates.
+///
+/// Anything at (12, 0, 12) is assumed to be the Dandelifeon, and anything else
+/// that looks like the flower is treated as a [`Cell::Blocked`].
+///
+/// # Errors
+///
+/// Returns an error if the coordinates are not on the board.
+pub(crate) fn place(
+    dish: &mut PetriDish,
+    palette: &Palette,
+    position: [i32; 3],
+    block: &str,
+) -> Result<(), FormatError> {
+    let [x, y, z] = position;
+    let (Ok(x), 0, Ok(z)) = (u8::try_from(x), y, u8::try_from(z)) else {
+        return Err(FormatError::WrongSize);
+    };
+    if x >= 25 || z >= 25 {
+        return Err(FormatError::WrongSize);
+    }
+    let (x, y): (u8, u8) = to_block(x, z);
+    if (x, y) == (12, 12) {
+        return Ok(());
+    }
+    match palette.cell(block) {
+        Cell::Dandelifeon => dish.set(x, y, Cell::Blocked)?,
+        cell @ (Cell::Dead | Cell::Living | Cell::Blocked) => {
+            dish.set(x, y, cell)?;
+        }
+    }
+    Ok(())
+}
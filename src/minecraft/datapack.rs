@@ -0,0 +1,293 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Zipped [datapacks] containing a single [`function`].
+//!
+//! Once the zip file is placed in `<world>/datapacks/`, the board can be built
+//! by standing where its corner should go and running
+//! `/function dandelifeon:build`.
+//!
+//! [datapacks]: https://minecraft.wiki/w/Data_pack
+
+use std::io::{self, Write};
+
+use flate2::write::DeflateEncoder;
+use flate2::{Compression, Crc};
+
+use crate::minecraft::{Palette, function};
+use crate::simulation::PetriDish;
+
+/// The pack format of Minecraft 1.20.1.
+const PACK_FORMAT: u8 = 15;
+
+/// The path of the function within the datapack.
+const FUNCTION_PATH: &str = "data/dandelifeon/functions/build.mcfunction";
+
+/// The version of the zip specification needed to extract the files, which is
+/// the first to support deflate.
+const ZIP_VERSION: u16 = 20;
+
+/// The compression method number of deflate.
+const DEFLATE: u16 = 8;
+
+/// The modification date of every file, 1980-01-01 in MS-DOS format. A fixed
+/// date keeps the output reproducible.
+const DOS_DATE: u16 = (1 << 5) | 1;
+
+/// Writes `dish` to `writer` as a zipped datapack, using `palette` to choose
+/// the blocks. The function inside is the same as the one written by
+/// [`function::write`], and `clear_area` has the same meaning.
+///
+/// # Errors
+///
+/// Returns an error if writing to `writer` fails.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::minecraft::{Palette, datapack};
+/// use dandelifeon::simulation::PetriDish;
+///
+/// let mut file: Vec<u8> = Vec::new();
+/// datapack::write(
+///     &PetriDish::OPTIMAL_100_ROUND,
+///     &Palette::new(),
+///     true,
+///     &mut file,
+/// )?;
+///
+/// assert!(file.starts_with(b"PK\x03\x04"), "missing local file header");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write<W: Write>(
+    dish: &PetriDish,
+    palette: &Palette,
+    clear_area: bool,
+    writer: W,
+) -> io::Result<()> {
+    let mut build: Vec<u8> = Vec::new();
+    function::write(dish, palette, clear_area, &mut build)?;
+    let meta: String = format!(
+        "{{\"pack\":{{\"pack_format\":{PACK_FORMAT},\"description\":\"Builds \
+         a Dandelifeon setup\"}}}}\n"
+    );
+
+    let mut archive: Archive<W> = Archive::new(writer);
+    archive.add("pack.mcmeta", meta.as_bytes())?;
+    archive.add(FUNCTION_PATH, &build)?;
+    archive.finish()
+}
+
+/// A minimal writer of zip files, supporting just enough to write a datapack.
+#[derive(Debug)]
+struct Archive<W: Write> {
+    /// Where the zip file is written.
+    writer: W,
+    /// The number of bytes written so far.
+    offset: u32,
+    /// The central directory, written once all of the files have been added.
+    directory: Vec<u8>,
+    /// The number of files added so far.
+    entries: u16,
+}
+
+impl<W: Write> Archive<W> {
+    /// Starts a new, empty zip file.
+    const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            offset: 0,
+            directory: Vec::new(),
+            entries: 0,
+        }
+    }
+
+    /// Compresses `contents` and adds it to the zip file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails, or if the zip file would need the
+    /// ZIP64 extensions.
+    fn add(&mut self, path: &str, contents: &[u8]) -> io::Result<()> {
+        let mut crc: Crc = Crc::new();
+        crc.update(contents);
+        let mut encoder: DeflateEncoder<Vec<u8>> =
+            DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(contents)?;
+        let compressed: Vec<u8> = encoder.finish()?;
+
+        let compressed_size: u32 = fit(compressed.len())?;
+        let size: u32 = fit(contents.len())?;
+        let path_length: u16 = fit(path.len())?;
+        // The fields shared by the local header and the central directory.
+        let mut common: Vec<u8> = Vec::new();
+        for field in [ZIP_VERSION, 0, DEFLATE, 0, DOS_DATE] {
+            common.extend(field.to_le_bytes());
+        }
+        for field in [crc.sum(), compressed_size, size] {
+            common.extend(field.to_le_bytes());
+        }
+        common.extend(path_length.to_le_bytes());
+        common.extend(0_u16.to_le_bytes());
+
+        let mut local: Vec<u8> = 0x0403_4B50_u32.to_le_bytes().to_vec();
+        local.extend(&common);
+        local.extend(path.as_bytes());
+        local.extend(&compressed);
+
+        self.directory.extend(0x0201_4B50_u32.to_le_bytes());
+        self.directory.extend(ZIP_VERSION.to_le_bytes());
+        self.directory.extend(&common);
+        // No comment, on the first disk, with no attributes.
+        self.directory.extend([0; 10]);
+        self.directory.extend(self.offset.to_le_bytes());
+        self.directory.extend(path.as_bytes());
+
+        self.writer.write_all(&local)?;
+        self.offset = self
+            .offset
+            .checked_add(fit(local.len())?)
+            .ok_or_else(too_large)?;
+        self.entries = self.entries.checked_add(1).ok_or_else(too_large)?;
+        Ok(())
+    }
+
+    /// Writes the central directory, completing the zip file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails, or if the zip file would need the
+    /// ZIP64 extensions.
+    fn finish(mut self) -> io::Result<()> {
+        let mut end: Vec<u8> = 0x0605_4B50_u32.to_le_bytes().to_vec();
+        for field in [0, 0, self.entries, self.entries] {
+            end.extend(field.to_le_bytes());
+        }
+        end.extend(fit::<u32>(self.directory.len())?.to_le_bytes());
+        end.extend(self.offset.to_le_bytes());
+        end.extend(0_u16.to_le_bytes());
+
+        self.writer.write_all(&self.directory)?;
+        self.writer.write_all(&end)?;
+        self.writer.flush()
+    }
+}
+
+/// Converts a length into the size of integer used by a zip file.
+///
+/// # Errors
+///
+/// Returns an error if the length is too large to fit.
+fn fit<T: TryFrom<usize>>(length: usize) -> io::Result<T> {
+    T::try_from(length).ok().ok_or_else(too_large)
+}
+
+/// The error returned when something is too large for a zip file without the
+/// ZIP64 extensions.
+fn too_large() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "too large for a zip file")
+}
+
+#[cfg(test)]
+mod tests {
+    //! Reads a datapack back, checking that it is a well-formed zip file.
+
+    use std::io::Read as _;
+
+    use flate2::read::DeflateDecoder;
+
+    use crate::minecraft::{Palette, datapack};
+    use crate::simulation::PetriDish;
+
+    /// Gets the little-endian field of `N` bytes at `at` in `file`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `file` ends before the field does.
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "fields are at most 4 bytes long and lie within the file"
+    )]
+    fn field<const N: usize>(file: &[u8], at: usize) -> usize {
+        file[at..at + N]
+            .iter()
+            .rev()
+            .fold(0, |value: usize, &byte: &u8| {
+                (value << 8) | usize::from(byte)
+            })
+    }
+
+    /// Walks the central directory, checking every entry and the local file
+    /// header it points to, and inflates `pack.mcmeta`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any signature is missing, or if the files or their contents
+    /// are not the ones that were written.
+    #[test]
+    fn datapack_reads_back() {
+        let mut file: Vec<u8> = Vec::new();
+        datapack::write(
+            &PetriDish::OPTIMAL_100_ROUND,
+            &Palette::new(),
+            true,
+            &mut file,
+        )
+        .unwrap();
+
+        // The end of central directory record is the last 22 bytes.
+        let end: usize = file.len() - 22;
+        assert_eq!(
+            field::<4>(&file, end),
+            0x0605_4B50,
+            "missing end of central directory"
+        );
+        let mut entry: usize = field::<4>(&file, end + 16);
+
+        let mut names: Vec<String> = Vec::new();
+        let mut meta: String = String::new();
+        for _ in 0..field::<2>(&file, end + 10) {
+            assert_eq!(
+                field::<4>(&file, entry),
+                0x0201_4B50,
+                "missing central directory entry"
+            );
+            let name_start: usize = entry + 46;
+            let name: &[u8] =
+                &file[name_start..name_start + field::<2>(&file, entry + 28)];
+            names.push(String::from_utf8(name.to_vec()).unwrap());
+
+            let local: usize = field::<4>(&file, entry + 42);
+            assert_eq!(
+                field::<4>(&file, local),
+                0x0403_4B50,
+                "missing local file header"
+            );
+            if name == b"pack.mcmeta" {
+                let data: usize = local
+                    + 30
+                    + field::<2>(&file, local + 26)
+                    + field::<2>(&file, local + 28);
+                let compressed: usize = field::<4>(&file, entry + 20);
+                let _: usize =
+                    DeflateDecoder::new(&file[data..data + compressed])
+                        .read_to_string(&mut meta)
+                        .unwrap();
+            }
+            entry = name_start
+                + name.len()
+                + field::<2>(&file, entry + 30)
+                + field::<2>(&file, entry + 32);
+        }
+
+        assert_eq!(
+            names,
+            ["pack.mcmeta", "data/dandelifeon/functions/build.mcfunction"]
+        );
+        assert!(
+            meta.starts_with("{\"pack\":{\"pack_format\":15,"),
+            "unexpected pack.mcmeta: {meta}"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [Function files] made of `setblock` commands.
+//!
+//! Every command is relative to the position the function is executed at, so
+//! running it places the board with its corner at the executor's feet and the
+//! Dandelifeon 12 blocks along both the x and z axes. This is lighter weight
+//! than the other formats, but needs commands to be enabled. Functions are
+//! usually run from a datapack; see [`datapack`](crate::minecraft::datapack).
+//!
+//! [Function files]: https://minecraft.wiki/w/Function_(Java_Edition)
+
+use std::io::{self, Write};
+
+use crate::minecraft::{Palette, to_block};
+use crate::simulation::{Cell, PetriDish};
+
+/// Writes `dish` to `writer` as a function, using `palette` to choose the
+/// blocks.
+///
+/// If `clear_area` is `true`, the function starts by filling the whole board
+/// with air. Otherwise, only the blocks that are not [`Cell::Dead`] are placed,
+/// and anything already in the way will behave as a [`Cell::Blocked`].
+///
+/// # Errors
+///
+/// Returns an error if writing to `writer` fails.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::minecraft::{Palette, function};
+/// use dandelifeon::simulation::PetriDish;
+///
+/// let mut file: Vec<u8> = Vec::new();
+/// function::write(
+///     &PetriDish::OPTIMAL_100_ROUND,
+///     &Palette::new(),
+///     true,
+///     &mut file,
+/// )?;
+/// let file: String = String::from_utf8(file)?;
+///
+/// let mut commands =
+///     file.lines().filter(|line: &&str| !line.starts_with('#'));
+/// assert_eq!(
+///     commands.next(),
+///     Some("fill ~0 ~0 ~0 ~24 ~0 ~24 minecraft:air")
+/// );
+/// assert!(
+///     commands.any(|command: &str| command
+///         == "setblock ~12 ~0 ~12 botania:dandelifeon")
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn write<W: Write>(
+    dish: &PetriDish,
+    palette: &Palette,
+    clear_area: bool,
+    mut writer: W,
+) -> io::Result<()> {
+    writeln!(writer, "# Generated by {}", env!("CARGO_PKG_NAME"))?;
+    if clear_area {
+        writeln!(writer, "fill ~0 ~0 ~0 ~24 ~0 ~24 {}", Palette::AIR)?;
+    }
+    for (x, y, cell) in dish.iter_cells() {
+        if cell == Cell::Dead {
+            continue;
+        }
+        let (x, z): (u8, u8) = to_block(x, y);
+        writeln!(writer, "setblock ~{x} ~0 ~{z} {}", palette.block(cell))?;
+    }
+    writer.flush()
+}
@@ -0,0 +1,239 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [Litematica] schematic files.
+//!
+//! Each file holds a single region named after the schematic, positioned at
+//! the origin. Block states are packed into [`i64`]s using the fewest bits that
+//! can index every entry of the palette, with entries allowed to straddle two
+//! [`i64`]s, as Litematica expects.
+//!
+//! [Litematica]: https://github.com/maruohon/litematica
+
+use std::io::{Read, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::minecraft::nbt::{self, Tag};
+use crate::minecraft::{
+    DATA_VERSION, FormatError, Palette, layer, layer_position, place,
+};
+use crate::simulation::{Cell, PetriDish};
+
+/// The version of the Litematica format that is written. This is the version
+/// used by Litematica for Minecraft 1.20.1.
+const VERSION: i32 = 6;
+
+/// The sub-version of the Litematica format that is written.
+const SUB_VERSION: i32 = 1;
+
+/// The name given to the schematic and its only region.
+const NAME: &str = "Dandelifeon";
+
+/// The number of bits used to store each block state. This is the minimum of 2
+/// that Litematica uses, which is exactly enough for the 4 entries of a
+/// [`Palette`].
+const BITS: u32 = 2;
+
+/// Writes `dish` to `writer` as a gzip-compressed Litematica schematic, using
+/// `palette` to choose the blocks.
+///
+/// # Errors
+///
+/// Returns an error if writing to `writer` fails.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::minecraft::{Palette, litematic};
+/// use dandelifeon::simulation::PetriDish;
+///
+/// let palette: Palette = Palette::new().with_blocker("minecraft:glass");
+/// let mut file: Vec<u8> = Vec::new();
+/// litematic::write(&PetriDish::OPTIMAL_100_ROUND, &palette, &mut file)?;
+///
+/// let dish: PetriDish = litematic::read(file.as_slice(), &palette)?;
+/// assert_eq!(dish, PetriDish::OPTIMAL_100_ROUND);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn write<W: Write>(
+    dish: &PetriDish,
+    palette: &Palette,
+    writer: W,
+) -> Result<(), nbt::Error> {
+    let mut states: Vec<i64> = Vec::new();
+    let mut total_blocks: i32 = 0;
+    for (index, cell) in (0_u32..).zip(layer(dish)) {
+        if cell != Cell::Dead {
+            total_blocks = total_blocks.saturating_add(1);
+        }
+        #[expect(
+            clippy::arithmetic_side_effects,
+            clippy::indexing_slicing,
+            reason = "index is at most 624, so none of this can overflow, and
+            states is grown to fit before it is indexed"
+        )]
+        {
+            let bit: u32 = index * BITS;
+            let (word, offset): (usize, u32) =
+                (bit.div_euclid(64) as usize, bit.rem_euclid(64));
+            states.resize(states.len().max(word + 1), 0);
+            let value: u64 = u64::from(cell as u8);
+            states[word] |= (value << offset).cast_signed();
+            if offset + BITS > 64 {
+                states.resize(states.len().max(word + 2), 0);
+                states[word + 1] |= (value >> (64 - offset)).cast_signed();
+            }
+        }
+    }
+
+    let now: i64 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|now| i64::try_from(now.as_millis()).ok())
+        .unwrap_or(0);
+    let vector = |x: i32, y: i32, z: i32| {
+        Tag::compound([
+            ("x", Tag::Int(x)),
+            ("y", Tag::Int(y)),
+            ("z", Tag::Int(z)),
+        ])
+    };
+    let block_states: Vec<Tag> = palette
+        .blocks()
+        .into_iter()
+        .map(|block: &str| Tag::compound([("Name", Tag::string(block))]))
+        .collect();
+
+    let root: Tag = Tag::compound([
+        ("MinecraftDataVersion", Tag::Int(DATA_VERSION)),
+        ("Version", Tag::Int(VERSION)),
+        ("SubVersion", Tag::Int(SUB_VERSION)),
+        (
+            "Metadata",
+            Tag::compound([
+                ("Name", Tag::string(NAME)),
+                ("Author", Tag::string(env!("CARGO_PKG_NAME"))),
+                ("Description", Tag::string("")),
+                ("RegionCount", Tag::Int(1)),
+                ("TotalBlocks", Tag::Int(total_blocks)),
+                ("TotalVolume", Tag::Int(25 * 25)),
+                ("TimeCreated", Tag::Long(now)),
+                ("TimeModified", Tag::Long(now)),
+                ("EnclosingSize", vector(25, 1, 25)),
+            ]),
+        ),
+        (
+            "Regions",
+            Tag::compound([(
+                NAME,
+                Tag::compound([
+                    ("Position", vector(0, 0, 0)),
+                    ("Size", vector(25, 1, 25)),
+                    ("BlockStatePalette", Tag::List(block_states)),
+                    ("BlockStates", Tag::LongArray(states)),
+                    ("Entities", Tag::List(Vec::new())),
+                    ("TileEntities", Tag::List(Vec::new())),
+                    ("PendingBlockTicks", Tag::List(Vec::new())),
+                    ("PendingFluidTicks", Tag::List(Vec::new())),
+                ]),
+            )]),
+        ),
+    ]);
+
+    let mut encoder: GzEncoder<W> = GzEncoder::new(writer, Compression::best());
+    nbt::write(&mut encoder, "", &root)?;
+    let _: W = encoder.finish()?;
+    Ok(())
+}
+
+/// Reads a gzip-compressed Litematica schematic back into a [`PetriDish`],
+/// using `palette` to tell the blocks apart. Only the first region is read.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, the file is not a valid schematic, or
+/// the first region is not a single 25 by 25 layer.
+pub fn read<R: Read>(
+    reader: R,
+    palette: &Palette,
+) -> Result<PetriDish, FormatError> {
+    let (_, root): (String, Tag) = nbt::read(GzDecoder::new(reader))?;
+    let regions: &Tag = root
+        .get("Regions")
+        .ok_or(FormatError::MissingField("Regions"))?;
+    let Tag::Compound(ref regions) = *regions else {
+        return Err(FormatError::MissingField("Regions"));
+    };
+    let region: &Tag = &regions
+        .first()
+        .ok_or(FormatError::MissingField("Regions"))?
+        .1;
+
+    let size: Option<[Option<i32>; 3]> =
+        region.get("Size").map(|size: &Tag| {
+            ["x", "y", "z"].map(|axis: &str| size.get(axis)?.as_int())
+        });
+    if size != Some([Some(25), Some(1), Some(25)]) {
+        return Err(FormatError::WrongSize);
+    }
+
+    let states: Vec<&str> = region
+        .get("BlockStatePalette")
+        .and_then(Tag::as_list)
+        .and_then(|states: &[Tag]| {
+            states
+                .iter()
+                .map(|state: &Tag| state.get("Name").and_then(Tag::as_str))
+                .collect()
+        })
+        .ok_or(FormatError::MissingField("BlockStatePalette"))?;
+    let words: &Tag = region
+        .get("BlockStates")
+        .ok_or(FormatError::MissingField("BlockStates"))?;
+    let Tag::LongArray(ref words) = *words else {
+        return Err(FormatError::MissingField("BlockStates"));
+    };
+
+    let bits: u32 = usize::BITS
+        .saturating_sub(states.len().saturating_sub(1).leading_zeros())
+        .max(BITS);
+    let mask: u64 = (1_u64 << bits).wrapping_sub(1);
+    let word = |index: usize| -> Result<u64, FormatError> {
+        words
+            .get(index)
+            .map(|word: &i64| word.cast_unsigned())
+            .ok_or(FormatError::MissingField("BlockStates"))
+    };
+
+    let mut dish: PetriDish = PetriDish::new();
+    for index in 0..25 * 25 {
+        #[expect(
+            clippy::arithmetic_side_effects,
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            reason = "index is at most 624 and bits is at most 64, so none of
+            this can overflow, and the offset is always below 64"
+        )]
+        let state: u64 = {
+            let bit: usize = index * bits as usize;
+            let (word_index, offset): (usize, u32) =
+                (bit.div_euclid(64), bit.rem_euclid(64) as u32);
+            let mut state: u64 = word(word_index)? >> offset;
+            if offset + bits > 64 {
+                state |= word(word_index + 1)? << (64 - offset);
+            }
+            state & mask
+        };
+        let name: &str = usize::try_from(state)
+            .ok()
+            .and_then(|state: usize| states.get(state))
+            .ok_or(FormatError::MissingField("BlockStatePalette"))?;
+        place(&mut dish, palette, layer_position(index), name)?;
+    }
+    Ok(dish)
+}
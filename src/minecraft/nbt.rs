@@ -0,0 +1,429 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A minimal implementation of Java Edition's [Named Binary Tag] format, which
+//! is what structure and schematic files are made of.
+//!
+//! Only uncompressed NBT is handled here. The files written by the rest of
+//! [`minecraft`](crate::minecraft) are gzip-compressed on top of this.
+//!
+//! [Named Binary Tag]: https://minecraft.wiki/w/NBT_format
+
+use core::error;
+use core::fmt::{self, Display, Formatter};
+use std::io::{self, Read, Write};
+
+/// The deepest nesting of [`Tag::List`]s and [`Tag::Compound`]s that will be
+/// read before giving up, to avoid overflowing the stack on malicious input.
+const MAX_DEPTH: usize = 512;
+
+/// An upper limit on how much is allocated up front when reading an array or
+/// list, to avoid trusting lengths read from the input.
+const MAX_PREALLOCATION: usize = 4096;
+
+/// A single NBT tag, without its name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tag {
+    /// A signed 8-bit integer.
+    Byte(i8),
+    /// A signed 16-bit integer.
+    Short(i16),
+    /// A signed 32-bit integer.
+    Int(i32),
+    /// A signed 64-bit integer.
+    Long(i64),
+    /// A 32-bit floating point number.
+    Float(f32),
+    /// A 64-bit floating point number.
+    Double(f64),
+    /// An array of signed 8-bit integers.
+    ByteArray(Vec<i8>),
+    /// A string.
+    String(String),
+    /// A list of unnamed tags, which must all be of the same kind.
+    List(Vec<Self>),
+    /// A collection of named tags, kept in the order they were written.
+    Compound(Vec<(String, Self)>),
+    /// An array of signed 32-bit integers.
+    IntArray(Vec<i32>),
+    /// An array of signed 64-bit integers.
+    LongArray(Vec<i64>),
+}
+
+impl Tag {
+    /// Builds a [`Tag::String`].
+    pub fn string<S: Into<String>>(value: S) -> Self {
+        Self::String(value.into())
+    }
+
+    /// Builds a [`Tag::Compound`] from `(name, tag)` pairs.
+    pub fn compound<const N: usize>(entries: [(&str, Self); N]) -> Self {
+        Self::Compound(
+            entries
+                .into_iter()
+                .map(|(name, tag): (&str, Self)| (name.to_owned(), tag))
+                .collect(),
+        )
+    }
+
+    /// If this is a [`Tag::Compound`], gets the first entry with the given
+    /// name.
+    pub fn get(&self, name: &str) -> Option<&Self> {
+        let Self::Compound(ref entries) = *self else {
+            return None;
+        };
+        entries
+            .iter()
+            .find(|entry: &&(String, Self)| entry.0 == name)
+            .map(|entry: &(String, Self)| &entry.1)
+    }
+
+    /// If this is a [`Tag::Byte`], [`Tag::Short`] or [`Tag::Int`], gets its
+    /// value.
+    pub fn as_int(&self) -> Option<i32> {
+        match *self {
+            Self::Byte(value) => Some(value.into()),
+            Self::Short(value) => Some(value.into()),
+            Self::Int(value) => Some(value),
+            Self::Long(_)
+            | Self::Float(_)
+            | Self::Double(_)
+            | Self::ByteArray(_)
+            | Self::String(_)
+            | Self::List(_)
+            | Self::Compound(_)
+            | Self::IntArray(_)
+            | Self::LongArray(_) => None,
+        }
+    }
+
+    /// If this is a [`Tag::String`], gets its value.
+    pub fn as_str(&self) -> Option<&str> {
+        let Self::String(ref value) = *self else {
+            return None;
+        };
+        Some(value)
+    }
+
+    /// If this is a [`Tag::List`], gets its elements.
+    pub fn as_list(&self) -> Option<&[Self]> {
+        let Self::List(ref value) = *self else {
+            return None;
+        };
+        Some(value)
+    }
+
+    /// The numeric ID used for this kind of tag in the binary format.
+    const fn id(&self) -> u8 {
+        match *self {
+            Self::Byte(_) => 1,
+            Self::Short(_) => 2,
+            Self::Int(_) => 3,
+            Self::Long(_) => 4,
+            Self::Float(_) => 5,
+            Self::Double(_) => 6,
+            Self::ByteArray(_) => 7,
+            Self::String(_) => 8,
+            Self::List(_) => 9,
+            Self::Compound(_) => 10,
+            Self::IntArray(_) => 11,
+            Self::LongArray(_) => 12,
+        }
+    }
+}
+
+/// An error encountered while reading or writing NBT.
+#[derive(Debug)]
+pub enum Error {
+    /// The underlying reader or writer failed.
+    Io(io::Error),
+    /// A tag ID which does not exist was read.
+    UnknownTag(u8),
+    /// The root tag was not a [`Tag::Compound`].
+    RootNotCompound,
+    /// A negative length was read.
+    NegativeLength,
+    /// A length was too large to be written.
+    TooLong,
+    /// A string was not valid UTF-8.
+    InvalidString,
+    /// [`Tag::List`]s and [`Tag::Compound`]s were nested more deeply than is
+    /// supported.
+    TooDeep,
+    /// A [`Tag::List`] to be written contained more than one kind of tag.
+    MixedList,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Io(ref error) => write!(f, "{error}"),
+            Self::UnknownTag(id) => write!(f, "unknown tag ID {id}"),
+            Self::RootNotCompound => write!(f, "root tag is not a compound"),
+            Self::NegativeLength => write!(f, "negative length"),
+            Self::TooLong => write!(f, "length too large to be written"),
+            Self::InvalidString => write!(f, "string is not valid UTF-8"),
+            Self::TooDeep => {
+                write!(f, "tags are nested more than {MAX_DEPTH} deep")
+            }
+            Self::MixedList => write!(f, "list contains more than one kind"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        let Self::Io(ref error) = *self else {
+            return None;
+        };
+        Some(error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// Writes `tag` as the named root tag of an NBT document. The root tag must be
+/// a [`Tag::Compound`].
+///
+/// # Errors
+///
+/// Returns an error if writing fails, if the root is not a
+/// [`Tag::Compound`], if a [`Tag::List`] contains more than one kind of tag,
+/// or if something is too long to be represented.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::minecraft::nbt::{self, Tag};
+///
+/// let tag: Tag = Tag::compound([("size", Tag::List(vec![Tag::Int(25)]))]);
+/// let mut bytes: Vec<u8> = Vec::new();
+/// nbt::write(&mut bytes, "", &tag)?;
+///
+/// assert_eq!(nbt::read(bytes.as_slice())?, (String::new(), tag));
+/// # Ok::<(), nbt::Error>(())
+/// ```
+pub fn write<W: Write>(
+    mut writer: W,
+    name: &str,
+    tag: &Tag,
+) -> Result<(), Error> {
+    if !matches!(*tag, Tag::Compound(_)) {
+        return Err(Error::RootNotCompound);
+    }
+    writer.write_all(&[tag.id()])?;
+    write_string(&mut writer, name)?;
+    write_payload(&mut writer, tag)
+}
+
+/// Reads an NBT document, returning the name and value of the root tag.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the input is not valid NBT.
+pub fn read<R: Read>(mut reader: R) -> Result<(String, Tag), Error> {
+    let [id]: [u8; 1] = read_array(&mut reader)?;
+    if id != 10 {
+        return Err(Error::RootNotCompound);
+    }
+    let name: String = read_string(&mut reader)?;
+    let tag: Tag = read_payload(&mut reader, id, 0)?;
+    Ok((name, tag))
+}
+
+/// Writes a length as a 32-bit integer.
+///
+/// # Errors
+///
+/// Returns an error if writing fails or `length` does not fit.
+fn write_length<W: Write>(writer: &mut W, length: usize) -> Result<(), Error> {
+    let length: i32 = i32::try_from(length).ok().ok_or(Error::TooLong)?;
+    Ok(writer.write_all(&length.to_be_bytes())?)
+}
+
+/// Writes a string prefixed with its length as a 16-bit integer.
+///
+/// # Errors
+///
+/// Returns an error if writing fails or `value` is too long.
+fn write_string<W: Write>(writer: &mut W, value: &str) -> Result<(), Error> {
+    let length: u16 = u16::try_from(value.len()).ok().ok_or(Error::TooLong)?;
+    writer.write_all(&length.to_be_bytes())?;
+    Ok(writer.write_all(value.as_bytes())?)
+}
+
+/// Writes the payload of a tag, without its ID or name.
+///
+/// # Errors
+///
+/// Returns an error if writing fails, a [`Tag::List`] contains more than one
+/// kind of tag, or something is too long to be represented.
+fn write_payload<W: Write>(writer: &mut W, tag: &Tag) -> Result<(), Error> {
+    match *tag {
+        Tag::Byte(value) => writer.write_all(&value.to_be_bytes())?,
+        Tag::Short(value) => writer.write_all(&value.to_be_bytes())?,
+        Tag::Int(value) => writer.write_all(&value.to_be_bytes())?,
+        Tag::Long(value) => writer.write_all(&value.to_be_bytes())?,
+        Tag::Float(value) => writer.write_all(&value.to_be_bytes())?,
+        Tag::Double(value) => writer.write_all(&value.to_be_bytes())?,
+        Tag::ByteArray(ref values) => {
+            write_length(writer, values.len())?;
+            for value in values {
+                writer.write_all(&value.to_be_bytes())?;
+            }
+        }
+        Tag::String(ref value) => write_string(writer, value)?,
+        Tag::List(ref values) => {
+            let id: u8 = values.first().map_or(0, Tag::id);
+            if values.iter().any(|value: &Tag| value.id() != id) {
+                return Err(Error::MixedList);
+            }
+            writer.write_all(&[id])?;
+            write_length(writer, values.len())?;
+            for value in values {
+                write_payload(writer, value)?;
+            }
+        }
+        Tag::Compound(ref entries) => {
+            for entry in entries {
+                writer.write_all(&[entry.1.id()])?;
+                write_string(writer, &entry.0)?;
+                write_payload(writer, &entry.1)?;
+            }
+            writer.write_all(&[0])?;
+        }
+        Tag::IntArray(ref values) => {
+            write_length(writer, values.len())?;
+            for value in values {
+                writer.write_all(&value.to_be_bytes())?;
+            }
+        }
+        Tag::LongArray(ref values) => {
+            write_length(writer, values.len())?;
+            for value in values {
+                writer.write_all(&value.to_be_bytes())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads exactly `N` bytes.
+///
+/// # Errors
+///
+/// Returns an error if reading fails.
+fn read_array<const N: usize, R: Read>(
+    reader: &mut R,
+) -> Result<[u8; N], Error> {
+    let mut bytes: [u8; N] = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads a length stored as a 32-bit integer.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the length is negative.
+fn read_length<R: Read>(reader: &mut R) -> Result<usize, Error> {
+    let length: i32 = i32::from_be_bytes(read_array(reader)?);
+    usize::try_from(length).ok().ok_or(Error::NegativeLength)
+}
+
+/// Reads a string prefixed with its length as a 16-bit integer.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the string is not valid UTF-8.
+fn read_string<R: Read>(reader: &mut R) -> Result<String, Error> {
+    let length: u16 = u16::from_be_bytes(read_array(reader)?);
+    let mut bytes: Vec<u8> = vec![0; length.into()];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).ok().ok_or(Error::InvalidString)
+}
+
+/// Reads `length` values using `read_one`.
+///
+/// # Errors
+///
+/// Returns the first error returned by `read_one`.
+fn read_many<T, R: Read, F: FnMut(&mut R) -> Result<T, Error>>(
+    reader: &mut R,
+    length: usize,
+    mut read_one: F,
+) -> Result<Vec<T>, Error> {
+    let mut values: Vec<T> = Vec::with_capacity(length.min(MAX_PREALLOCATION));
+    for _ in 0..length {
+        values.push(read_one(reader)?);
+    }
+    Ok(values)
+}
+
+/// Reads the payload of a tag with the given ID.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the input is not valid NBT.
+fn read_payload<R: Read>(
+    reader: &mut R,
+    id: u8,
+    depth: usize,
+) -> Result<Tag, Error> {
+    if depth > MAX_DEPTH {
+        return Err(Error::TooDeep);
+    }
+    let depth: usize = depth.saturating_add(1);
+    Ok(match id {
+        1 => Tag::Byte(i8::from_be_bytes(read_array(reader)?)),
+        2 => Tag::Short(i16::from_be_bytes(read_array(reader)?)),
+        3 => Tag::Int(i32::from_be_bytes(read_array(reader)?)),
+        4 => Tag::Long(i64::from_be_bytes(read_array(reader)?)),
+        5 => Tag::Float(f32::from_be_bytes(read_array(reader)?)),
+        6 => Tag::Double(f64::from_be_bytes(read_array(reader)?)),
+        7 => {
+            let length: usize = read_length(reader)?;
+            Tag::ByteArray(read_many(reader, length, |reader: &mut R| {
+                Ok(i8::from_be_bytes(read_array(reader)?))
+            })?)
+        }
+        8 => Tag::String(read_string(reader)?),
+        9 => {
+            let [element]: [u8; 1] = read_array(reader)?;
+            let length: usize = read_length(reader)?;
+            Tag::List(read_many(reader, length, |reader: &mut R| {
+                read_payload(reader, element, depth)
+            })?)
+        }
+        10 => {
+            let mut entries: Vec<(String, Tag)> = Vec::new();
+            loop {
+                let [element]: [u8; 1] = read_array(reader)?;
+                if element == 0 {
+                    break;
+                }
+                let name: String = read_string(reader)?;
+                entries.push((name, read_payload(reader, element, depth)?));
+            }
+            Tag::Compound(entries)
+        }
+        11 => {
+            let length: usize = read_length(reader)?;
+            Tag::IntArray(read_many(reader, length, |reader: &mut R| {
+                Ok(i32::from_be_bytes(read_array(reader)?))
+            })?)
+        }
+        12 => {
+            let length: usize = read_length(reader)?;
+            Tag::LongArray(read_many(reader, length, |reader: &mut R| {
+                Ok(i64::from_be_bytes(read_array(reader)?))
+            })?)
+        }
+        _ => return Err(Error::UnknownTag(id)),
+    })
+}
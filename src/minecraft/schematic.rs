@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [Sponge schematic] files, as used by `WorldEdit` and friends.
+//!
+//! Both version 2 and version 3 of the format can be written, and either can be
+//! read back. `WorldEdit` 7.3 and later prefer version 3, while older versions
+//! only understand version 2.
+//!
+//! [Sponge schematic]: https://github.com/SpongePowered/Schematic-Specification
+
+use std::io::{Read, Write};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::minecraft::nbt::{self, Tag};
+use crate::minecraft::{
+    DATA_VERSION, FormatError, Palette, layer, layer_position, place,
+};
+use crate::simulation::{Cell, PetriDish};
+
+/// The versions of the Sponge schematic format that can be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Version {
+    /// Version 2, understood by `WorldEdit` 7.0 and later.
+    V2,
+    /// Version 3, understood by `WorldEdit` 7.3 and later.
+    #[default]
+    V3,
+}
+
+/// Writes `dish` to `writer` as a gzip-compressed Sponge schematic of the
+/// given [`Version`], using `palette` to choose the blocks.
+///
+/// # Errors
+///
+/// Returns an error if writing to `writer` fails.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::minecraft::Palette;
+/// use dandelifeon::minecraft::schematic::{self, Version};
+/// use dandelifeon::simulation::PetriDish;
+///
+/// let palette: Palette = Palette::new();
+/// for version in [Version::V2, Version::V3] {
+///     let mut file: Vec<u8> = Vec::new();
+///     schematic::write(
+///         &PetriDish::OPTIMAL_100_ROUND,
+///         &palette,
+///         version,
+///         &mut file,
+///     )?;
+///
+///     let dish: PetriDish = schematic::read(file.as_slice(), &palette)?;
+///     assert_eq!(dish, PetriDish::OPTIMAL_100_ROUND);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn write<W: Write>(
+    dish: &PetriDish,
+    palette: &Palette,
+    version: Version,
+    writer: W,
+) -> Result<(), nbt::Error> {
+    let states: Tag = Tag::Compound(
+        palette
+            .blocks()
+            .into_iter()
+            .zip(0..)
+            .map(|(block, index): (&str, i32)| {
+                (block.to_owned(), Tag::Int(index))
+            })
+            .collect(),
+    );
+    // Every index is below 128, so each varint is a single byte.
+    let data: Tag = Tag::ByteArray(
+        layer(dish)
+            .map(|cell: Cell| (cell as u8).cast_signed())
+            .collect(),
+    );
+
+    let (name, root): (&str, Tag) = match version {
+        Version::V2 => (
+            "Schematic",
+            Tag::compound([
+                ("Version", Tag::Int(2)),
+                ("DataVersion", Tag::Int(DATA_VERSION)),
+                ("Width", Tag::Short(25)),
+                ("Height", Tag::Short(1)),
+                ("Length", Tag::Short(25)),
+                ("PaletteMax", Tag::Int(4)),
+                ("Palette", states),
+                ("BlockData", data),
+                ("BlockEntities", Tag::List(Vec::new())),
+            ]),
+        ),
+        Version::V3 => (
+            "",
+            Tag::compound([(
+                "Schematic",
+                Tag::compound([
+                    ("Version", Tag::Int(3)),
+                    ("DataVersion", Tag::Int(DATA_VERSION)),
+                    ("Width", Tag::Short(25)),
+                    ("Height", Tag::Short(1)),
+                    ("Length", Tag::Short(25)),
+                    ("Offset", Tag::IntArray(vec![0, 0, 0])),
+                    (
+                        "Blocks",
+                        Tag::compound([
+                            ("Palette", states),
+                            ("Data", data),
+                            ("BlockEntities", Tag::List(Vec::new())),
+                        ]),
+                    ),
+                ]),
+            )]),
+        ),
+    };
+
+    let mut encoder: GzEncoder<W> = GzEncoder::new(writer, Compression::best());
+    nbt::write(&mut encoder, name, &root)?;
+    let _: W = encoder.finish()?;
+    Ok(())
+}
+
+/// Reads a gzip-compressed Sponge schematic of either [`Version`] back into a
+/// [`PetriDish`], using `palette` to tell the blocks apart.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, the file is not a valid schematic, or
+/// the schematic is not a single 25 by 25 layer.
+pub fn read<R: Read>(
+    reader: R,
+    palette: &Palette,
+) -> Result<PetriDish, FormatError> {
+    let (_, root): (String, Tag) = nbt::read(GzDecoder::new(reader))?;
+    // Version 3 wraps everything in another compound.
+    let schematic: &Tag = root.get("Schematic").unwrap_or(&root);
+
+    let size: [Option<i32>; 3] = ["Width", "Height", "Length"]
+        .map(|field: &str| schematic.get(field).and_then(Tag::as_int));
+    if size != [Some(25), Some(1), Some(25)] {
+        return Err(FormatError::WrongSize);
+    }
+
+    let (states, data): (&Tag, &Tag) = match schematic.get("Blocks") {
+        Some(blocks) => (
+            blocks
+                .get("Palette")
+                .ok_or(FormatError::MissingField("Palette"))?,
+            blocks
+                .get("Data")
+                .ok_or(FormatError::MissingField("Data"))?,
+        ),
+        None => (
+            schematic
+                .get("Palette")
+                .ok_or(FormatError::MissingField("Palette"))?,
+            schematic
+                .get("BlockData")
+                .ok_or(FormatError::MissingField("BlockData"))?,
+        ),
+    };
+
+    let Tag::Compound(ref states) = *states else {
+        return Err(FormatError::MissingField("Palette"));
+    };
+    let Tag::ByteArray(ref data) = *data else {
+        return Err(FormatError::MissingField("BlockData"));
+    };
+
+    let mut dish: PetriDish = PetriDish::new();
+    let mut bytes = data.iter().map(|byte: &i8| byte.cast_unsigned());
+    let mut index: usize = 0;
+    while let Some(state) = read_varint(&mut bytes)? {
+        let name: &str = states
+            .iter()
+            .find(|entry: &&(String, Tag)| entry.1.as_int() == Some(state))
+            .map(|entry: &(String, Tag)| entry.0.as_str())
+            .ok_or(FormatError::MissingField("Palette"))?;
+        place(&mut dish, palette, layer_position(index), name)?;
+        index = index.saturating_add(1);
+    }
+    Ok(dish)
+}
+
+/// Reads a single variable length integer, as used for block data. Returns
+/// [`None`] if there are no bytes left.
+///
+/// # Errors
+///
+/// Returns an error if the input ends in the middle of an integer, or the
+/// integer does not fit in an [`i32`].
+fn read_varint<I: Iterator<Item = u8>>(
+    bytes: &mut I,
+) -> Result<Option<i32>, FormatError> {
+    let mut value: u32 = 0;
+    let mut shift: u32 = 0;
+    let mut started: bool = false;
+    for byte in bytes.by_ref() {
+        started = true;
+        let bits: u32 = u32::from(byte & 0x7F);
+        value |= bits
+            .checked_shl(shift)
+            .filter(|shifted: &u32| shifted >> shift == bits)
+            .ok_or(FormatError::MissingField("BlockData"))?;
+        if byte & 0x80 == 0 {
+            return Ok(Some(value.cast_signed()));
+        }
+        shift = shift.saturating_add(7);
+    }
+    if started {
+        Err(FormatError::MissingField("BlockData"))
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Vanilla [structure files], as saved and loaded by structure blocks.
+//!
+//! Structure files are gzip-compressed NBT, and are placed in-game by putting
+//! them in `<world>/generated/<namespace>/structures/` and loading them with a
+//! structure block.
+//!
+//! [structure files]: https://minecraft.wiki/w/Structure_file
+
+use std::io::{Read, Write};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::minecraft::nbt::{self, Tag};
+use crate::minecraft::{DATA_VERSION, FormatError, Palette, place, to_block};
+use crate::simulation::{Cell, PetriDish};
+
+/// Writes `dish` to `writer` as a gzip-compressed structure file, using
+/// `palette` to choose the blocks.
+///
+/// Every [`Cell::Dead`] is written as air, so loading the structure clears out
+/// anything already in the way.
+///
+/// # Errors
+///
+/// Returns an error if writing to `writer` fails.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::minecraft::{Palette, structure};
+/// use dandelifeon::simulation::PetriDish;
+///
+/// let palette: Palette = Palette::new().with_blocker("minecraft:cobblestone");
+/// let mut file: Vec<u8> = Vec::new();
+/// structure::write(&PetriDish::OPTIMAL_100_ROUND, &palette, &mut file)?;
+///
+/// let dish: PetriDish = structure::read(file.as_slice(), &palette)?;
+/// assert_eq!(dish, PetriDish::OPTIMAL_100_ROUND);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn write<W: Write>(
+    dish: &PetriDish,
+    palette: &Palette,
+    writer: W,
+) -> Result<(), nbt::Error> {
+    let blocks: Vec<Tag> = dish
+        .iter_cells()
+        .map(|(x, y, cell): (u8, u8, Cell)| {
+            let (x, z): (u8, u8) = to_block(x, y);
+            Tag::compound([
+                (
+                    "pos",
+                    Tag::List(vec![
+                        Tag::Int(x.into()),
+                        Tag::Int(0),
+                        Tag::Int(z.into()),
+                    ]),
+                ),
+                ("state", Tag::Int((cell as u8).into())),
+            ])
+        })
+        .collect();
+    let states: Vec<Tag> = palette
+        .blocks()
+        .into_iter()
+        .map(|block: &str| Tag::compound([("Name", Tag::string(block))]))
+        .collect();
+
+    let root: Tag = Tag::compound([
+        ("DataVersion", Tag::Int(DATA_VERSION)),
+        (
+            "size",
+            Tag::List(vec![Tag::Int(25), Tag::Int(1), Tag::Int(25)]),
+        ),
+        ("palette", Tag::List(states)),
+        ("blocks", Tag::List(blocks)),
+        ("entities", Tag::List(Vec::new())),
+    ]);
+
+    let mut encoder: GzEncoder<W> = GzEncoder::new(writer, Compression::best());
+    nbt::write(&mut encoder, "", &root)?;
+    let _: W = encoder.finish()?;
+    Ok(())
+}
+
+/// Reads a gzip-compressed structure file back into a [`PetriDish`], using
+/// `palette` to tell the blocks apart. Positions without a block are treated
+/// as [`Cell::Dead`].
+///
+/// # Errors
+///
+/// Returns an error if reading fails, the file is not a valid structure file,
+/// or the structure is not a single 25 by 25 layer.
+pub fn read<R: Read>(
+    reader: R,
+    palette: &Palette,
+) -> Result<PetriDish, FormatError> {
+    let (_, root): (String, Tag) = nbt::read(GzDecoder::new(reader))?;
+
+    let size: Vec<i32> = root
+        .get("size")
+        .and_then(Tag::as_list)
+        .and_then(|size: &[Tag]| size.iter().map(Tag::as_int).collect())
+        .ok_or(FormatError::MissingField("size"))?;
+    if size != [25, 1, 25] {
+        return Err(FormatError::WrongSize);
+    }
+
+    let states: Vec<&str> = root
+        .get("palette")
+        .and_then(Tag::as_list)
+        .and_then(|states: &[Tag]| {
+            states
+                .iter()
+                .map(|state: &Tag| state.get("Name").and_then(Tag::as_str))
+                .collect()
+        })
+        .ok_or(FormatError::MissingField("palette"))?;
+
+    let mut dish: PetriDish = PetriDish::new();
+    for block in root
+        .get("blocks")
+        .and_then(Tag::as_list)
+        .ok_or(FormatError::MissingField("blocks"))?
+    {
+        let position: [i32; 3] = block
+            .get("pos")
+            .and_then(Tag::as_list)
+            .and_then(|position: &[Tag]| match *position {
+                [ref x, ref y, ref z] => {
+                    Some([x.as_int()?, y.as_int()?, z.as_int()?])
+                }
+                _ => None,
+            })
+            .ok_or(FormatError::MissingField("pos"))?;
+        let name: &str = block
+            .get("state")
+            .and_then(Tag::as_int)
+            .and_then(|state: i32| usize::try_from(state).ok())
+            .and_then(|state: usize| states.get(state))
+            .ok_or(FormatError::MissingField("state"))?;
+        place(&mut dish, palette, position, name)?;
+    }
+    Ok(dish)
+}
@@ -0,0 +1,295 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Keeping track of the trade-offs between mana, cost and speed.
+//!
+//! A [`Score`] ranks boards strictly by mana first, so a board with slightly
+//! more mana always beats a much cheaper one. A [`ParetoFront`] instead keeps
+//! every board that no other board beats on all of its [`Objectives`] at once,
+//! leaving the choice of trade-off until the search is done.
+
+use core::fmt::{self, Debug, Display, Formatter};
+
+use crate::bees::{Event, Observer, StoppingCondition};
+use crate::fitness::{CostModel, Fitness, ManaPerStep};
+use crate::simulation::{GameReport, PetriDish};
+use crate::{Hive, Score};
+
+/// Everything a [`ParetoFront`] weighs a board by.
+///
+/// The mana it generates should be as high as possible, while the
+/// [`Cell::Living`] and [`Cell::Blocked`] it costs and the steps it takes
+/// should all be as low as possible.
+///
+/// [`Cell::Living`]: crate::simulation::Cell::Living
+/// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Objectives {
+    /// The mana generated by the game.
+    mana: u16,
+    /// The initial cell cost of the game.
+    cell_cost: u16,
+    /// The initial "blocker" cost of the game.
+    block_cost: u16,
+    /// The number of steps the game took.
+    steps: u8,
+}
+
+impl Objectives {
+    /// Gets the mana generated by the game.
+    pub const fn mana(self) -> u16 {
+        self.mana
+    }
+
+    /// Gets the initial cell cost of the game.
+    pub const fn cell_cost(self) -> u16 {
+        self.cell_cost
+    }
+
+    /// Gets the initial "blocker" cost of the game.
+    pub const fn block_cost(self) -> u16 {
+        self.block_cost
+    }
+
+    /// Gets the number of steps the game took.
+    pub const fn steps(self) -> u8 {
+        self.steps
+    }
+
+    /// Checks whether these [`Objectives`] are at least as good as `other` in
+    /// every way, and better in at least one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::pareto::Objectives;
+    /// use dandelifeon::simulation::{GameReport, PetriDish};
+    ///
+    /// let record: Objectives =
+    ///     Objectives::from(GameReport::new(&PetriDish::OPTIMAL_100_ROUND));
+    /// let empty: Objectives =
+    ///     Objectives::from(GameReport::new(&PetriDish::new()));
+    ///
+    /// // More mana, but a higher cost, so neither dominates the other.
+    /// assert!(!record.dominates(empty));
+    /// assert!(!empty.dominates(record));
+    /// assert!(!record.dominates(record));
+    /// ```
+    pub const fn dominates(self, other: Self) -> bool {
+        let at_least_as_good: bool = self.mana >= other.mana
+            && self.cell_cost <= other.cell_cost
+            && self.block_cost <= other.block_cost
+            && self.steps <= other.steps;
+        let better: bool = self.mana > other.mana
+            || self.cell_cost < other.cell_cost
+            || self.block_cost < other.block_cost
+            || self.steps < other.steps;
+        at_least_as_good && better
+    }
+}
+
+impl Display for Objectives {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({} mana, cost {} cells and {} blocks, {} steps)",
+            self.mana, self.cell_cost, self.block_cost, self.steps
+        )
+    }
+}
+
+impl From<GameReport> for Objectives {
+    fn from(value: GameReport) -> Self {
+        let (cell_cost, block_cost): (u16, u16) = value.initial_cost();
+        Self {
+            mana: value.mana(),
+            cell_cost,
+            block_cost,
+            steps: value.steps(),
+        }
+    }
+}
+
+impl<const STEPS_FIRST: bool, C: CostModel> From<Score<STEPS_FIRST, C>>
+    for Objectives
+{
+    fn from(value: Score<STEPS_FIRST, C>) -> Self {
+        Self {
+            mana: value.mana(),
+            cell_cost: value.cell_cost(),
+            block_cost: value.block_cost(),
+            steps: value.steps(),
+        }
+    }
+}
+
+impl From<ManaPerStep> for Objectives {
+    fn from(value: ManaPerStep) -> Self {
+        Self {
+            mana: value.mana(),
+            cell_cost: value.cell_cost(),
+            block_cost: value.block_cost(),
+            steps: value.steps(),
+        }
+    }
+}
+
+/// An archive of the [`PetriDish`]es whose [`Objectives`] are not dominated
+/// by those of any other [`PetriDish`] it has been offered.
+///
+/// Of several [`PetriDish`]es with exactly the same [`Objectives`], only the
+/// first is kept.
+///
+/// As an [`Observer`], it is offered every [`PetriDish`] a search evaluates,
+/// through [`Event::Evaluated`], with its [`Objectives`] taken from the
+/// [`Fitness`] it was evaluated with rather than playing it again. That takes
+/// a [`Fitness`] which keeps every one of the [`Objectives`], such as a
+/// [`Score`] or a [`ManaPerStep`].
+///
+/// It is only an archive kept alongside the search, which is still steered by
+/// its [`Fitness`] alone. So the front is made up of the boards that search
+/// happened to evaluate on its way, not the true front of every board.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::Hive;
+/// use dandelifeon::bees::{Colony, Event, MaxIterations, Scout};
+/// use dandelifeon::pareto::{Objectives, ParetoFront};
+/// use dandelifeon::simulation::PetriDish;
+/// use rand::SeedableRng;
+/// use rand::rngs::SmallRng;
+///
+/// type Search = Hive<MaxIterations>;
+///
+/// let mut hive: Search = Hive::new(MaxIterations::new(2));
+/// let mut rng: SmallRng = SmallRng::seed_from_u64(21);
+/// let mut observer: (ParetoFront, Vec<Event<Search>>) =
+///     (ParetoFront::new(), Vec::new());
+/// let best: Scout<Search> =
+///     hive.bees(&Search::CONFIG, &mut rng, &mut observer);
+/// let (mut front, events) = observer;
+///
+/// // Every board the search evaluated is either in the front, or matched or
+/// // beaten by something that is.
+/// for event in events {
+///     if let Event::Evaluated(scout) = event {
+///         let objectives: Objectives = Objectives::from(scout.fitness());
+///         assert!(front.members().iter().any(|&(_, member)| {
+///             member == objectives || member.dominates(objectives)
+///         }));
+///     }
+/// }
+///
+/// // The empty board costs nothing, so nothing can dominate it.
+/// assert!(front.offer(PetriDish::new()));
+/// assert!(!front.offer(PetriDish::new()));
+///
+/// let members: &[(PetriDish, Objectives)] = front.members();
+/// assert!(members.iter().all(|(_, first)| {
+///     members.iter().all(|(_, second)| !first.dominates(*second))
+/// }));
+/// assert!(members.iter().any(|&(dish, _)| dish == best.solution()));
+/// ```
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "a front on its own could be the front of anything"
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ParetoFront {
+    /// The non-dominated [`PetriDish`]es, in the order they were found.
+    members: Vec<(PetriDish, Objectives)>,
+}
+
+impl ParetoFront {
+    /// Creates a new, empty [`ParetoFront`].
+    pub const fn new() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+
+    /// Plays a game starting from `dish`, and adds it to this [`ParetoFront`]
+    /// if nothing in it dominates or matches its [`Objectives`]. Anything it
+    /// dominates is removed. Returns whether it was added.
+    pub fn offer(&mut self, dish: PetriDish) -> bool {
+        self.insert(dish, Objectives::from(GameReport::new(&dish)))
+    }
+
+    /// Gets every [`PetriDish`] in this [`ParetoFront`] with its
+    /// [`Objectives`], in the order they were added.
+    pub fn members(&self) -> &[(PetriDish, Objectives)] {
+        &self.members
+    }
+
+    /// Gets every [`PetriDish`] in this [`ParetoFront`] with its
+    /// [`Objectives`], in the order they were added.
+    pub fn into_members(self) -> Vec<(PetriDish, Objectives)> {
+        self.members
+    }
+
+    /// Gets the number of [`PetriDish`]es in this [`ParetoFront`].
+    pub const fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Checks whether this [`ParetoFront`] is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Adds `dish` if nothing already here dominates or matches `objectives`,
+    /// removing anything it dominates. Returns whether it was added.
+    fn insert(&mut self, dish: PetriDish, objectives: Objectives) -> bool {
+        if self
+            .members
+            .iter()
+            .any(|&(_, member): &(PetriDish, Objectives)| {
+                member == objectives || member.dominates(objectives)
+            })
+        {
+            return false;
+        }
+        self.members
+            .retain(|&(_, member): &(PetriDish, Objectives)| {
+                !objectives.dominates(member)
+            });
+        self.members.push((dish, objectives));
+        true
+    }
+}
+
+impl Extend<PetriDish> for ParetoFront {
+    /// Offers every [`PetriDish`] like [`ParetoFront::offer`], but plays all
+    /// of their games as one batch with [`PetriDish::play_many`].
+    fn extend<T: IntoIterator<Item = PetriDish>>(&mut self, iter: T) {
+        let dishes: Vec<PetriDish> = iter
+            .into_iter()
+            .filter(|dish: &PetriDish| {
+                !self.members.iter().any(
+                    |&(member, _): &(PetriDish, Objectives)| member == *dish,
+                )
+            })
+            .collect();
+        let mut games: Vec<PetriDish> = dishes.clone();
+        let reports: Vec<GameReport> = PetriDish::play_many(&mut games);
+        for (dish, report) in dishes.into_iter().zip(reports) {
+            let _: bool = self.insert(dish, Objectives::from(report));
+        }
+    }
+}
+
+impl<S, F> Observer<Hive<S, F>> for ParetoFront
+where
+    S: StoppingCondition<F> + Debug + Copy,
+    F: Fitness,
+    Objectives: From<F>,
+{
+    fn observe(&mut self, event: Event<Hive<S, F>>) {
+        if let Event::Evaluated(scout) = event {
+            let _: bool = self
+                .insert(scout.solution(), Objectives::from(scout.fitness()));
+        }
+    }
+}
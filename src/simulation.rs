@@ -5,11 +5,31 @@
 //! Types for representing and simulating the modified Game of Life used by the
 //! Dandelifeon.
 
+use core::error::Error;
 use core::fmt::{self, Display, Formatter};
 
 use rand::Rng;
 use rand::distr::{Distribution, StandardUniform};
 
+pub use crate::simulation::aged::AgedDish;
+use crate::simulation::game::Recorder;
+pub use crate::simulation::game::{Ending, GameReport, Simulation, Step};
+pub use crate::simulation::rle::{Rle, RleError};
+pub use crate::simulation::text::{
+    Compact, ParseDishError, ParseDishErrorKind,
+};
+
+mod aged;
+mod game;
+mod rle;
+mod swar;
+mod text;
+
+/// The low bit of every [`Cell`] in a row of a [`PetriDish`]. Masking a row
+/// with this after clearing the cells whose high bit is set leaves one bit for
+/// each [`Cell::Living`].
+pub(crate) const LOW_BITS: u64 = 0x0001_5555_5555_5555;
+
 /// A [`PetriDish`] is a compact representation of the Dandelifeon game board.
 ///
 /// Internally, it is represented by a [`u64`] array of length 25. Each [`u64`]
@@ -48,11 +68,7 @@ impl PetriDish {
     /// algorithm in 2016.
     ///
     /// [the setup found by Cobra1117]: https://www.reddit.com/r/botania/comments/5by0jl/optimal_100round_dandelifeon_setup/
-    #[expect(
-        dead_code,
-        reason = "Didn't want to use this when generating solutions to present."
-    )]
-    pub(crate) const OPTIMAL_100_ROUND: Self = {
+    pub const OPTIMAL_100_ROUND: Self = {
         let mut current_record: Self = Self::new();
 
         current_record.set_blocked(7, 14);
@@ -73,9 +89,27 @@ impl PetriDish {
         current_record
     };
 
+    /// The most steps [`PetriDish::play`] and [`Simulation`] will simulate
+    /// before giving up on a game.
+    pub const MAX_STEPS: u8 = 102;
+
+    /// The coordinates of the uninhabitable zone around the
+    /// [`Cell::Dandelifeon`]. A [`Cell::Living`] that grows into any of these
+    /// ends the game and is converted into mana.
+    pub const LETHAL_ZONE: [(u8, u8); 8] = [
+        (11, 11),
+        (12, 11),
+        (13, 11),
+        (11, 12),
+        (13, 12),
+        (11, 13),
+        (12, 13),
+        (13, 13),
+    ];
+
     /// Creates a new [`PetriDish`] where all cells are [`Cell::Dead`] except
     /// for the [`Cell::Dandelifeon`].
-    const fn new() -> Self {
+    pub const fn new() -> Self {
         let mut new: Self = Self::new_with([0; 25]);
         new.set_dandelifeon();
         new
@@ -87,6 +121,175 @@ impl PetriDish {
         Self(state)
     }
 
+    /// Creates a new [`PetriDish`] from an iterator of `(x, y, cell)` triples,
+    /// such as the one returned by [`PetriDish::iter_cells`]. Any coordinates
+    /// not mentioned are left as [`Cell::Dead`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`PetriDish::set`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::PetriDish;
+    ///
+    /// let record: PetriDish = PetriDish::OPTIMAL_100_ROUND;
+    /// let copy: PetriDish = PetriDish::from_cells(record.iter_cells())?;
+    ///
+    /// assert_eq!(record, copy);
+    /// # Ok::<(), dandelifeon::simulation::DishError>(())
+    /// ```
+    pub fn from_cells<I: IntoIterator<Item = (u8, u8, Cell)>>(
+        cells: I,
+    ) -> Result<Self, DishError> {
+        let mut dish: Self = Self::new();
+        for (x, y, cell) in cells {
+            dish.set(x, y, cell)?;
+        }
+        Ok(dish)
+    }
+
+    /// Returns an iterator over every [`Cell`] on the board as `(x, y, cell)`
+    /// triples, row by row starting from `y = 0`.
+    pub fn iter_cells(&self) -> impl Iterator<Item = (u8, u8, Cell)> {
+        let dish: Self = *self;
+        (0..25_u8).flat_map(move |y: u8| {
+            (0..25_u8)
+                .map(move |x: u8| (x, y, Cell::from_bits(dish.read(x, y))))
+        })
+    }
+
+    /// Gets the [`Cell`] at the given coordinates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DishError::OutOfBounds`] if either coordinate is not in the
+    /// range `0..25`.
+    pub const fn get(&self, x: u8, y: u8) -> Result<Cell, DishError> {
+        if x >= 25 || y >= 25 {
+            return Err(DishError::OutOfBounds { x, y });
+        }
+        Ok(Cell::from_bits(self.read(x, y)))
+    }
+
+    /// Sets the [`Cell`] at the given coordinates.
+    ///
+    /// Setting (12, 12) to [`Cell::Dandelifeon`] is accepted and does nothing,
+    /// which allows the output of [`PetriDish::iter_cells`] to be fed straight
+    /// back in.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DishError::OutOfBounds`] if either coordinate is not in the
+    /// range `0..25`.
+    ///
+    /// Returns [`DishError::OverwritesDandelifeon`] if the coordinates are
+    /// (12, 12) and `cell` is not [`Cell::Dandelifeon`].
+    ///
+    /// Returns [`DishError::MisplacedDandelifeon`] if `cell` is
+    /// [`Cell::Dandelifeon`] and the coordinates are not (12, 12).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::{Cell, DishError, PetriDish};
+    ///
+    /// let mut dish: PetriDish = PetriDish::new();
+    /// dish.set(3, 4, Cell::Living)?;
+    ///
+    /// assert_eq!(dish.get(3, 4), Ok(Cell::Living));
+    /// assert_eq!(
+    ///     dish.set(25, 4, Cell::Blocked),
+    ///     Err(DishError::OutOfBounds { x: 25, y: 4 })
+    /// );
+    /// assert_eq!(
+    ///     dish.set(12, 12, Cell::Dead),
+    ///     Err(DishError::OverwritesDandelifeon)
+    /// );
+    /// # Ok::<(), DishError>(())
+    /// ```
+    pub const fn set(
+        &mut self,
+        x: u8,
+        y: u8,
+        cell: Cell,
+    ) -> Result<(), DishError> {
+        if x >= 25 || y >= 25 {
+            return Err(DishError::OutOfBounds { x, y });
+        }
+        match (x, y, cell) {
+            (12, 12, Cell::Dandelifeon) => (),
+            (12, 12, _) => return Err(DishError::OverwritesDandelifeon),
+            (_, _, Cell::Dandelifeon) => {
+                return Err(DishError::MisplacedDandelifeon { x, y });
+            }
+            (_, _, Cell::Dead) => self.set_dead(x, y),
+            (_, _, Cell::Living) => self.set_living(x, y),
+            (_, _, Cell::Blocked) => self.set_blocked(x, y),
+        }
+        Ok(())
+    }
+
+    /// Builder-style version of [`PetriDish::set`], which consumes and returns
+    /// the [`PetriDish`] so that calls may be chained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`PetriDish::set`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::{Cell, DishError, PetriDish};
+    ///
+    /// let blinker: PetriDish = PetriDish::new()
+    ///     .with(2, 3, Cell::Living)?
+    ///     .with(3, 3, Cell::Living)?
+    ///     .with(4, 3, Cell::Living)?;
+    ///
+    /// assert_eq!(blinker.count_living_and_blocked(), (3, 0));
+    /// # Ok::<(), DishError>(())
+    /// ```
+    pub const fn with(
+        mut self,
+        x: u8,
+        y: u8,
+        cell: Cell,
+    ) -> Result<Self, DishError> {
+        match self.set(x, y, cell) {
+            Ok(()) => Ok(self),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns a wrapper that displays this [`PetriDish`] in the compact,
+    /// one character per [`Cell`] text format. Both this and the regular
+    /// [`Display`] output can be parsed back with [`str::parse`].
+    pub const fn compact(self) -> Compact {
+        Compact::new(self)
+    }
+
+    /// Returns a wrapper that can display this [`PetriDish`] as Golly RLE. See
+    /// [`Rle`] for details of the format.
+    pub const fn rle(self) -> Rle {
+        Rle::new(self)
+    }
+
+    /// Makes a new [`PetriDish`] with the [`Cell`]s of `other` wherever the
+    /// low bit of their 2 bit slot is set in the matching row of `mask`, and
+    /// the [`Cell`]s of this one everywhere else. The high bits of `mask` are
+    /// ignored, as in [`LOW_BITS`].
+    pub(crate) fn splice(self, other: Self, mask: [u64; 25]) -> Self {
+        let mut rows: [u64; 25] = self.0;
+        for ((row, other), mask) in rows.iter_mut().zip(other.0).zip(mask) {
+            let low: u64 = mask & LOW_BITS;
+            let cells: u64 = low | (low << 1);
+            *row = (*row & !cells) | (other & cells);
+        }
+        Self(rows)
+    }
+
     /// Reads the [`Cell`] at the given coordinates as a [`u8`]. If the
     /// coordinates given are out of bounds, reads a [`Cell::Dead`].
     const fn read(&self, x: u8, y: u8) -> u8 {
@@ -187,7 +390,7 @@ impl PetriDish {
     #[expect(
         clippy::arithmetic_side_effects,
         clippy::indexing_slicing,
-        reason = "only used in PetriDish::reduce_cells where x and y are
+        reason = "only used in PetriDish::step_scalar where x and y are
         guaranteed to be in the range 0..25."
     )]
     const fn write_assume_clean_slate(&mut self, x: u8, y: u8, value: u8) {
@@ -206,9 +409,9 @@ impl PetriDish {
     #[expect(
         clippy::arithmetic_side_effects,
         clippy::indexing_slicing,
-        reason = "only used in Hive::explore using coordinates from
-        PetriDish::NONCENTER_COORDS, where x and y are guaranteed to be in the
-        range 0..25."
+        reason = "only used in Hive::explore and genetic mutation using
+        coordinates from PetriDish::NONCENTER_COORDS and while parsing text,
+        where x and y are guaranteed to be in the range 0..25."
     )]
     pub(crate) const fn write(&mut self, x: u8, y: u8, value: u8) {
         self.set_dead(x, y);
@@ -319,6 +522,11 @@ impl PetriDish {
         let blocked: bool = center == Cell::BLOCKED;
         let three_living_neighbors: bool = count_neighbors == 3;
         let two_living_neighbors: bool = count_neighbors == 2;
+        #[expect(
+            clippy::needless_bitwise_bool,
+            reason = "the non-short-circuiting operators are intentional, this
+            is meant to be branchless"
+        )]
         let next_alive: bool = (center_alive
             & (three_living_neighbors | two_living_neighbors))
             | (center_dead & three_living_neighbors);
@@ -329,14 +537,36 @@ impl PetriDish {
         (u8::from(blocked_or_dandelifeon) << 1) | u8::from(alive_or_dandelifeon)
     }
 
-    /// Simulates a single step of the game for the entire [`PetriDish`].
+    /// Simulates a single step of the game for the entire [`PetriDish`],
+    /// updating a whole row of [`Cell`]s at a time.
     ///
     /// The transition rules are:
     ///  1) Any live cell with exactly 2 or 3 live neighbours survives the step.
     ///  2) Any live cell not satisfying condition 1 becomes dead.
     ///  3) Any dead cell with exactly three live neighbours becomes a live
     ///     cell.
-    fn reduce_cells(self) -> (Self, Status) {
+    ///
+    /// If a [`Cell::Living`] is already in the uninhabitable zone, the board
+    /// is returned unchanged along with [`Status::AbruptEnd`].
+    pub fn step(self) -> (Self, Status) {
+        swar::step(&self)
+    }
+
+    /// Simulates a single step of the game for the entire [`PetriDish`], one
+    /// [`Cell`] at a time.
+    ///
+    /// This is much slower than [`PetriDish::step`] (`cargo bench` times the
+    /// two), and is kept as a simple reference to check it against.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::PetriDish;
+    ///
+    /// let dish: PetriDish = PetriDish::OPTIMAL_100_ROUND;
+    /// assert_eq!(dish.step_scalar(), dish.step());
+    /// ```
+    pub fn step_scalar(self) -> (Self, Status) {
         let x_dandelifeon: u8 = 12;
         let y_dandelifeon: u8 = 12;
 
@@ -361,7 +591,7 @@ impl PetriDish {
             .iter()
             .any(|dangerous_cell: &u8| matches!(*dangerous_cell, Cell::LIVING))
         {
-            return (self, Status::AbrubtEnd);
+            return (self, Status::AbruptEnd);
         }
 
         let mut game_over: Status = Status::Continue;
@@ -414,68 +644,148 @@ impl PetriDish {
     /// Counts the number of [`Cell::Living`] and [`Cell::Blocked`] on the
     /// board. If the game has not started, this is equivalent to the initial
     /// investment. The return is (living, blocked).
-    pub(crate) fn count_living_and_blocked(self) -> (u16, u16) {
-        let mut count_cells: u16 = 0;
-        let mut count_blocked: u16 = 0;
-        for x in 0..25 {
-            for y in 0..25 {
-                #[expect(
-                    clippy::arithmetic_side_effects,
-                    reason = "at most, any of these fires 25 * 25 = 625 times.
-                    this cannot overflow a u16."
-                )]
-                match self.read(x, y) {
-                    Cell::LIVING => {
-                        count_cells += 1;
-                    }
-                    Cell::BLOCKED => {
-                        count_blocked += 1;
-                    }
-                    Cell::DANDELIFEON | Cell::DEAD => (),
-                    #[expect(
-                        clippy::unreachable,
-                        reason = "read returns values masked by 0b11, which we
-                        already exhaustively covered"
-                    )]
-                    _ => unreachable!(
-                        "read returns values masked by 0b11, which we already exhaustively covered"
+    pub fn count_living_and_blocked(self) -> (u16, u16) {
+        let (living, blocked): (u32, u32) =
+            self.0.iter().fold((0, 0), |(living, blocked), &row: &u64| {
+                (
+                    living.saturating_add(
+                        (row & !(row >> 1) & LOW_BITS).count_ones(),
                     ),
-                }
-            }
+                    blocked.saturating_add(
+                        ((row >> 1) & !row & LOW_BITS).count_ones(),
+                    ),
+                )
+            });
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::as_conversions,
+            reason = "there are at most 625 cells on the board, this cannot
+            truncate"
+        )]
+        (living as u16, blocked as u16)
+    }
+
+    /// Runs a simulation of the board for up to [`PetriDish::MAX_STEPS`]
+    /// iterations, or until the game ends, whichever comes first. Returns the
+    /// mana generated. If the game failed to complete, returns 0.
+    ///
+    /// Every [`Cell::Living`] is assumed to be the same age; see [`AgedDish`]
+    /// for a simulation that tracks the age of each one. To see how the game
+    /// plays out step by step, use a [`Simulation`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::PetriDish;
+    ///
+    /// let mut dish: PetriDish = PetriDish::OPTIMAL_100_ROUND;
+    /// assert_eq!(dish.play(), 36_000);
+    /// ```
+    pub fn play(&mut self) -> u16 {
+        let mut simulation: Simulation = Simulation::new(*self);
+        for step in simulation.by_ref() {
+            *self = step.dish();
         }
-        (count_cells, count_blocked)
+        simulation.ending().map_or(0, Ending::mana)
     }
 
-    /// Runs a simulation of the board for around 101 iterations, or until the
-    /// game ends, whichever comes first. Returns the mana generated. If the
-    /// game failed to complete, returns 0.
-    pub(crate) fn play(&mut self) -> u16 {
-        let mut iters: u8 = 0;
+    /// Counts the number of [`Cell::Living`] on the board.
+    const fn population(&self) -> u16 {
+        let mut population: u32 = 0;
+        let mut y: usize = 0;
+        while y < 25 {
+            #[expect(
+                clippy::indexing_slicing,
+                reason = "y is always in the range 0..25, it cannot panic"
+            )]
+            let row: u64 = self.0[y];
+            population = population
+                .saturating_add((row & !(row >> 1) & LOW_BITS).count_ones());
+            y = y.saturating_add(1);
+        }
         #[expect(
-            clippy::arithmetic_side_effects,
-            reason = "
-            end is Status, which can only represent 0, 1, or 2
-            end is guaranteed to be greater than 0 before subtracting 1 from it
-            iters is always at least 1 by the time we subtract from it
-            1 - 1 = 0, iters - 0 is always OK
-            2 - 1 = 1, iters - 1 is always OK
-            loop breaks before iters could ever overflow"
+            clippy::cast_possible_truncation,
+            clippy::as_conversions,
+            reason = "there are at most 625 cells on the board, this cannot
+            truncate"
         )]
-        loop {
-            let end: Status;
-            (*self, end) = self.reduce_cells();
-            iters += 1;
+        let population: u16 = population as u16;
+        population
+    }
 
-            if end as u8 > 0 {
-                let age: u8 = (iters - (end as u8 - 1)).min(100);
-                let score: u16 = self.score(age);
+    /// Plays a game from every one of `dishes` at once, reporting on how each
+    /// went. Like [`PetriDish::play`], each board is left as it was at the end
+    /// of its game.
+    ///
+    /// The games are played in lock-step. Each pass steps every unfinished
+    /// board with [`PetriDish::step`], one after another in a tight loop, and
+    /// only then records the steps and retires the games that ended. The
+    /// reports are exactly those of [`GameReport::new`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::{GameReport, PetriDish};
+    /// use rand::rngs::SmallRng;
+    /// use rand::{Rng, SeedableRng};
+    ///
+    /// let mut rng: SmallRng = SmallRng::seed_from_u64(12);
+    /// let mut dishes: Vec<PetriDish> = (0..100).map(|_| rng.random()).collect();
+    /// dishes.push(PetriDish::OPTIMAL_100_ROUND);
+    /// let expected: Vec<GameReport> =
+    ///     dishes.iter().map(GameReport::new).collect();
+    ///
+    /// assert_eq!(PetriDish::play_many(&mut dishes), expected);
+    /// ```
+    pub fn play_many(dishes: &mut [Self]) -> Vec<GameReport> {
+        let mut recorders: Vec<Recorder> =
+            dishes.iter().copied().map(Recorder::new).collect();
+        // The unfinished boards, each with the index of its game, kept
+        // together so that each pass steps them in one go.
+        let mut playing: Vec<(usize, Self)> =
+            dishes.iter().copied().enumerate().collect();
+        let mut steps: Vec<(Self, Status)> = Vec::with_capacity(playing.len());
+        while !playing.is_empty() {
+            steps.clear();
+            steps.extend(playing.iter().map(|&(_, dish)| dish.step()));
+
+            let mut steps_taken = steps.iter();
+            playing.retain_mut(|&mut (index, ref mut dish)| {
+                let Some(&(next, status)) = steps_taken.next() else {
+                    return false;
+                };
+                *dish = next;
+                recorders.get_mut(index).is_some_and(
+                    |recorder: &mut Recorder| recorder.record(next, status),
+                )
+            });
+        }
 
-                break score;
-            }
-            if iters > 101 {
-                break 0;
+        dishes
+            .iter_mut()
+            .zip(recorders)
+            .map(|(dish, recorder): (&mut Self, Recorder)| {
+                *dish = recorder.dish();
+                recorder.finish()
+            })
+            .collect()
+    }
+
+    /// Checks whether there are no [`Cell::Living`] left on the board.
+    const fn is_extinct(&self) -> bool {
+        let mut y: usize = 0;
+        while y < 25 {
+            #[expect(
+                clippy::indexing_slicing,
+                reason = "y is always in the range 0..25, it cannot panic"
+            )]
+            let row: u64 = self.0[y];
+            if row & !(row >> 1) & LOW_BITS != 0 {
+                return false;
             }
+            y = y.saturating_add(1);
         }
+        true
     }
 
     /// Calculates the mana generated by the game ending in the current board
@@ -671,7 +981,8 @@ pub enum Cell {
     ///
     /// In practice, this means that any game with no mid-game interference will
     /// contain only cells with an age equal to minimum between the number of
-    /// steps elapsed and 100. Thus, we do not store the age with the cells.
+    /// steps elapsed and 100. Thus, [`PetriDish`] does not store the age with
+    /// the cells. [`AgedDish`] does, for when that assumption does not hold.
     Living,
 
     /// A [`Cell::Blocked`] is a cell blocked off by the player or environment.
@@ -712,6 +1023,27 @@ impl Cell {
     /// A constant containing the value of [`Cell::Dandelifeon`] cast to a [`u8`]. For
     /// use in pattern matching while avoiding magic numbers.
     pub(crate) const DANDELIFEON: u8 = Self::Dandelifeon as u8;
+
+    /// Converts a [`u8`] read from a [`PetriDish`] back into a [`Cell`]. Only
+    /// the 2 least significant bits are considered.
+    pub(crate) const fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            Self::DEAD => Self::Dead,
+            Self::LIVING => Self::Living,
+            Self::BLOCKED => Self::Blocked,
+            _ => Self::Dandelifeon,
+        }
+    }
+
+    /// The character used to represent this [`Cell`] in text.
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Dead => '.',
+            Self::Living => 'O',
+            Self::Blocked => 'X',
+            Self::Dandelifeon => '*',
+        }
+    }
 }
 
 const _: () = const {
@@ -735,17 +1067,67 @@ const _: () = const {
     );
 };
 
+/// An error encountered while constructing or editing a [`PetriDish`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DishError {
+    /// The given coordinates are outside of the 25 by 25 board.
+    OutOfBounds {
+        /// The offending x coordinate.
+        x: u8,
+        /// The offending y coordinate.
+        y: u8,
+    },
+    /// Something other than a [`Cell::Dandelifeon`] was written to (12, 12),
+    /// which is always occupied by the Dandelifeon.
+    OverwritesDandelifeon,
+    /// A [`Cell::Dandelifeon`] was written somewhere other than (12, 12).
+    MisplacedDandelifeon {
+        /// The offending x coordinate.
+        x: u8,
+        /// The offending y coordinate.
+        y: u8,
+    },
+    /// An age was given to a [`Cell`] that is not [`Cell::Living`].
+    NotLiving {
+        /// The offending x coordinate.
+        x: u8,
+        /// The offending y coordinate.
+        y: u8,
+    },
+}
+
+impl Display for DishError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::OutOfBounds { x, y } => {
+                write!(f, "({x}, {y}) is outside of the 25 by 25 board")
+            }
+            Self::OverwritesDandelifeon => {
+                write!(f, "(12, 12) is occupied by the Dandelifeon")
+            }
+            Self::MisplacedDandelifeon { x, y } => write!(
+                f,
+                "the Dandelifeon can only be at (12, 12), not ({x}, {y})"
+            ),
+            Self::NotLiving { x, y } => {
+                write!(f, "only living cells have an age, ({x}, {y}) is not")
+            }
+        }
+    }
+}
+
+impl Error for DishError {}
+
 /// The result of running a step of the game.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
-enum Status {
+pub enum Status {
     /// No [`Cell`]s have entered the uninhabited zone. Keep going!
     Continue = 0,
-    /// A [`Cell`] has entered the uninhabited zone, or no [`Cell::Living`]
-    /// remains. Game end.
+    /// A [`Cell`] has entered the uninhabited zone. Game end.
     NormalEnd = 1,
     /// A [`Cell`] started in the uninhabited zone. Game end, award no mana.
-    AbrubtEnd = 2,
+    AbruptEnd = 2,
 }
 
 impl Display for Status {
@@ -753,7 +1135,99 @@ impl Display for Status {
         match *self {
             Self::Continue => write!(f, "Continue"),
             Self::NormalEnd => write!(f, "NormalEnd"),
-            Self::AbrubtEnd => write!(f, "AbruptEnd"),
+            Self::AbruptEnd => write!(f, "AbruptEnd"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Property tests checking [`PetriDish::step`] against
+    //! [`PetriDish::step_scalar`].
+
+    use rand::rngs::SmallRng;
+    use rand::{Rng as _, SeedableRng as _};
+
+    use crate::simulation::{PetriDish, Status};
+
+    /// Builds an arbitrary board with the given density.
+    ///
+    /// Every [`Cell`] other than the [`Cell::Dandelifeon`] is
+    /// [`Cell::Living`] with odds of `living` in 100, otherwise
+    /// [`Cell::Blocked`] with odds of `blocked` in 100, and [`Cell::Dead`]
+    /// otherwise. Unlike [`PetriDish`]'s [`Distribution`] impl, this reaches
+    /// nearly empty and nearly full boards, and ones with living cells in the
+    /// uninhabitable zone.
+    ///
+    /// [`Cell`]: crate::simulation::Cell
+    /// [`Cell::Dandelifeon`]: crate::simulation::Cell::Dandelifeon
+    /// [`Cell::Living`]: crate::simulation::Cell::Living
+    /// [`Cell::Blocked`]: crate::simulation::Cell::Blocked
+    /// [`Cell::Dead`]: crate::simulation::Cell::Dead
+    /// [`Distribution`]: rand::distr::Distribution
+    fn arbitrary(rng: &mut SmallRng, living: u32, blocked: u32) -> PetriDish {
+        let mut dish: PetriDish = PetriDish::new();
+        for (x, y) in PetriDish::NONCENTER_COORDS {
+            if rng.random_ratio(living, 100) {
+                dish.set_living(x, y);
+            } else if rng.random_ratio(blocked, 100) {
+                dish.set_blocked(x, y);
+            } else {
+                dish.set_dead(x, y);
+            }
+        }
+        dish
+    }
+
+    /// Plays `dish` to the end with both steppers, checking that they agree
+    /// on every step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the steppers disagree, naming the step and `case`.
+    fn check_game(mut dish: PetriDish, case: &str) {
+        for step in 0..PetriDish::MAX_STEPS {
+            let fast: (PetriDish, Status) = dish.step();
+            let scalar: (PetriDish, Status) = dish.step_scalar();
+            assert_eq!(
+                fast, scalar,
+                "steppers disagree on step {step} of {case}, from\n{dish}"
+            );
+            if fast.1 != Status::Continue {
+                return;
+            }
+            dish = fast.0;
+        }
+    }
+
+    /// Empty, fully living and fully blocked boards.
+    #[test]
+    fn steppers_agree_on_extremes() {
+        let mut rng: SmallRng = SmallRng::seed_from_u64(0);
+        for (living, blocked) in [(0, 0), (100, 0), (0, 100), (50, 100)] {
+            let dish: PetriDish = arbitrary(&mut rng, living, blocked);
+            check_game(dish, &format!("{living}% living, {blocked}% blocked"));
+        }
+    }
+
+    /// Boards of every density, seeded so that failures are reproducible.
+    #[test]
+    fn steppers_agree_on_arbitrary_games() {
+        for seed in 0..1_000 {
+            let mut rng: SmallRng = SmallRng::seed_from_u64(seed);
+            let living: u32 = rng.random_range(0..=100);
+            let blocked: u32 = rng.random_range(0..=100);
+            let dish: PetriDish = arbitrary(&mut rng, living, blocked);
+            check_game(dish, &format!("case {seed}"));
+        }
+    }
+
+    /// Boards like the ones the search starts from.
+    #[test]
+    fn steppers_agree_on_random_games() {
+        for seed in 0..1_000 {
+            let mut rng: SmallRng = SmallRng::seed_from_u64(seed);
+            check_game(rng.random(), &format!("case {seed}"));
         }
     }
 }
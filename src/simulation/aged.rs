@@ -0,0 +1,239 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A slower simulation that keeps track of the age of every [`Cell`].
+
+use crate::simulation::{Cell, DishError, PetriDish, Status};
+
+/// A [`PetriDish`] that also stores the age of every [`Cell::Living`].
+///
+/// [`PetriDish::play`] assumes that every [`Cell::Living`] has the same age,
+/// which only holds if every [`Cell::Living`] starts at age 0 and the board is
+/// left alone until the game ends. An [`AgedDish`] applies the real rules
+/// instead: a [`Cell::Living`] that survives a step gets one step older, and a
+/// [`Cell::Living`] that is born is one step older than its oldest neighbor.
+/// Either way, the age is capped at [`AgedDish::MAX_AGE`]. This allows
+/// simulating pre-aged cells and mid-game interference, at the cost of speed.
+///
+/// # Example
+///
+/// When nothing interferes, the two simulations agree:
+///
+/// ```rust
+/// use dandelifeon::simulation::{AgedDish, PetriDish};
+/// use rand::rngs::SmallRng;
+/// use rand::{Rng, SeedableRng};
+///
+/// let mut rng: SmallRng = SmallRng::seed_from_u64(7);
+/// let dishes = (0..200)
+///     .map(|_| rng.random::<PetriDish>())
+///     .chain([PetriDish::OPTIMAL_100_ROUND]);
+///
+/// for dish in dishes {
+///     assert_eq!(AgedDish::new(dish).play(), dish.clone().play());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AgedDish {
+    /// The [`Cell`]s on the board.
+    dish: PetriDish,
+    /// The age of every [`Cell`], indexed by y and then x. Anything that is not
+    /// a [`Cell::Living`] has an age of 0.
+    ages: [[u8; 25]; 25],
+}
+
+impl AgedDish {
+    /// The oldest a [`Cell::Living`] can get.
+    pub const MAX_AGE: u8 = 100;
+
+    /// Creates a new [`AgedDish`] from the given [`PetriDish`], where every
+    /// [`Cell::Living`] has an age of 0, as if it had just been placed.
+    pub const fn new(dish: PetriDish) -> Self {
+        Self {
+            dish,
+            ages: [[0; 25]; 25],
+        }
+    }
+
+    /// Gets the board without the ages.
+    pub const fn dish(&self) -> PetriDish {
+        self.dish
+    }
+
+    /// Gets the age of the [`Cell`] at the given coordinates. Anything that is
+    /// not a [`Cell::Living`] has an age of 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DishError::OutOfBounds`] if either coordinate is not in the
+    /// range `0..25`.
+    pub fn age(&self, x: u8, y: u8) -> Result<u8, DishError> {
+        let _: Cell = self.dish.get(x, y)?;
+        Ok(self.read_age(x, y))
+    }
+
+    /// Sets the [`Cell`] at the given coordinates, just like
+    /// [`PetriDish::set`]. The new [`Cell`] has an age of 0, as if it had just
+    /// been placed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`PetriDish::set`].
+    pub fn set(&mut self, x: u8, y: u8, cell: Cell) -> Result<(), DishError> {
+        self.dish.set(x, y, cell)?;
+        self.write_age(x, y, 0);
+        Ok(())
+    }
+
+    /// Sets the age of the [`Cell::Living`] at the given coordinates. Ages
+    /// above [`AgedDish::MAX_AGE`] are capped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DishError::OutOfBounds`] if either coordinate is not in the
+    /// range `0..25`.
+    ///
+    /// Returns [`DishError::NotLiving`] if the [`Cell`] at the given
+    /// coordinates is not a [`Cell::Living`].
+    ///
+    /// # Example
+    ///
+    /// A glider that is already 40 steps old is worth more than a fresh one.
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::{AgedDish, Cell, DishError, PetriDish};
+    ///
+    /// let glider: [(u8, u8); 5] = [(5, 3), (6, 4), (4, 5), (5, 5), (6, 5)];
+    /// let mut fresh: AgedDish = AgedDish::new(PetriDish::new());
+    /// for (x, y) in glider {
+    ///     fresh.set(x, y, Cell::Living)?;
+    /// }
+    /// let mut aged: AgedDish = fresh;
+    /// for (x, y) in glider {
+    ///     aged.set_age(x, y, 40)?;
+    /// }
+    ///
+    /// let (fresh, aged): (u16, u16) = (fresh.play(), aged.play());
+    /// assert!(fresh > 0, "the glider should reach the Dandelifeon");
+    /// assert!(aged > fresh, "older cells should be worth more mana");
+    /// assert_eq!(
+    ///     AgedDish::new(PetriDish::new()).set_age(0, 0, 40),
+    ///     Err(DishError::NotLiving { x: 0, y: 0 })
+    /// );
+    /// # Ok::<(), DishError>(())
+    /// ```
+    pub fn set_age(&mut self, x: u8, y: u8, age: u8) -> Result<(), DishError> {
+        match self.dish.get(x, y)? {
+            Cell::Living => {
+                self.write_age(x, y, age.min(Self::MAX_AGE));
+                Ok(())
+            }
+            Cell::Dead | Cell::Blocked | Cell::Dandelifeon => {
+                Err(DishError::NotLiving { x, y })
+            }
+        }
+    }
+
+    /// Simulates a single step of the game, following the same rules as
+    /// [`PetriDish::play`] while keeping track of ages. Returns the mana
+    /// generated if this step ended the game, or [`None`] if it goes on.
+    pub fn step(&mut self) -> Option<u16> {
+        let (next, status): (PetriDish, Status) = self.dish.step();
+        let ended: bool = match status {
+            Status::Continue => false,
+            Status::NormalEnd => true,
+            Status::AbruptEnd => return Some(0),
+        };
+
+        let mut ages: [[u8; 25]; 25] = [[0; 25]; 25];
+        for (x, y, cell) in next.iter_cells() {
+            if cell != Cell::Living {
+                continue;
+            }
+            let age: u8 = if self.dish.read(x, y) == Cell::LIVING {
+                self.read_age(x, y)
+            } else {
+                self.oldest_neighbor(x, y)
+            };
+            if let Some(slot) = ages
+                .get_mut(usize::from(y))
+                .and_then(|row: &mut [u8; 25]| row.get_mut(usize::from(x)))
+            {
+                *slot = age.saturating_add(1).min(Self::MAX_AGE);
+            }
+        }
+        self.dish = next;
+        self.ages = ages;
+
+        ended.then(|| self.mana())
+    }
+
+    /// Runs a simulation of the board for as many steps as
+    /// [`PetriDish::play`], or until the game ends, whichever comes first.
+    /// Returns the mana generated. If the game failed to complete, returns 0.
+    pub fn play(&mut self) -> u16 {
+        (0..PetriDish::MAX_STEPS)
+            .find_map(|_| self.step())
+            .unwrap_or(0)
+    }
+
+    /// Reads the age of the [`Cell`] at the given coordinates. If the
+    /// coordinates given are out of bounds, reads 0.
+    fn read_age(&self, x: u8, y: u8) -> u8 {
+        self.ages
+            .get(usize::from(y))
+            .and_then(|row: &[u8; 25]| row.get(usize::from(x)))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Writes the age of the [`Cell`] at the given coordinates. If the
+    /// coordinates given are out of bounds, does nothing.
+    fn write_age(&mut self, x: u8, y: u8, age: u8) {
+        if let Some(slot) = self
+            .ages
+            .get_mut(usize::from(y))
+            .and_then(|row: &mut [u8; 25]| row.get_mut(usize::from(x)))
+        {
+            *slot = age;
+        }
+    }
+
+    /// Finds the age of the oldest [`Cell::Living`] neighboring the given
+    /// coordinates.
+    fn oldest_neighbor(&self, x: u8, y: u8) -> u8 {
+        let mut oldest: u8 = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if let (Some(x), Some(y)) =
+                    (x.checked_add_signed(dx), y.checked_add_signed(dy))
+                {
+                    oldest = oldest.max(self.read_age(x, y));
+                }
+            }
+        }
+        oldest
+    }
+
+    /// Calculates the mana generated by the game ending in the current board
+    /// state, using the age of each consumed [`Cell::Living`].
+    fn mana(&self) -> u16 {
+        let mut mana: u16 = 0;
+        for y in 11..14 {
+            for x in 11..14 {
+                if self.dish.read(x, y) == Cell::LIVING {
+                    let age: u16 = u16::from(self.read_age(x, y));
+                    mana = mana.saturating_add(age.saturating_mul(60));
+                }
+            }
+        }
+        mana
+    }
+}
+
+impl From<PetriDish> for AgedDish {
+    fn from(value: PetriDish) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,454 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Step-by-step simulation of a game, for inspecting how it plays out.
+
+use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
+
+use crate::simulation::{Cell, PetriDish, Status};
+
+/// An [`Iterator`] over every step of a game, starting from a [`PetriDish`].
+///
+/// Each item is the board after a step, along with the [`Status`] of that
+/// step. Once the game is over, [`Simulation::ending`] reports how it ended.
+/// The rules are exactly those of [`PetriDish::play`], which is implemented
+/// on top of this.
+///
+/// Games that can no longer generate any mana, because every
+/// [`Cell::Living`] has died or the board has started repeating itself, are
+/// ended early rather than running until [`PetriDish::MAX_STEPS`].
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::simulation::{
+///     Ending, PetriDish, Simulation, Status, Step,
+/// };
+///
+/// let mut simulation: Simulation =
+///     Simulation::new(PetriDish::OPTIMAL_100_ROUND);
+/// let last: Option<Step> = simulation.by_ref().last();
+///
+/// assert_eq!(
+///     last.map(|step: Step| step.status()),
+///     Some(Status::NormalEnd)
+/// );
+/// assert_eq!(simulation.ending(), Some(Ending::Normal { mana: 36_000 }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Simulation {
+    /// The board as of the most recent step.
+    dish: PetriDish,
+    /// The number of steps taken so far.
+    steps: u8,
+    /// How the game ended, if it has.
+    ending: Option<Ending>,
+    /// A recent board to compare new boards against, to detect cycles.
+    checkpoint: PetriDish,
+    /// The number of steps since [`Simulation::checkpoint`] was taken.
+    since_checkpoint: u8,
+    /// The number of steps until [`Simulation::checkpoint`] is next moved.
+    checkpoint_interval: u8,
+}
+
+impl Simulation {
+    /// Creates a new [`Simulation`] of a game starting from `dish`.
+    pub const fn new(dish: PetriDish) -> Self {
+        Self {
+            dish,
+            steps: 0,
+            ending: None,
+            checkpoint: dish,
+            since_checkpoint: 0,
+            checkpoint_interval: 1,
+        }
+    }
+
+    /// Gets the board as of the most recent step.
+    pub const fn dish(&self) -> PetriDish {
+        self.dish
+    }
+
+    /// Gets the number of steps taken so far.
+    pub const fn steps(&self) -> u8 {
+        self.steps
+    }
+
+    /// Gets how the game ended, or [`None`] if it is still going.
+    pub const fn ending(&self) -> Option<Ending> {
+        self.ending
+    }
+
+    /// Checks whether the current board has been seen before, returning the
+    /// period of the cycle if so.
+    ///
+    /// This is Brent's algorithm: the current board is compared against a
+    /// single checkpoint, which is moved forward after exponentially longer
+    /// intervals. A cycle is found within a few periods of the board entering
+    /// it, without having to remember every board.
+    fn find_cycle(&mut self) -> Option<u8> {
+        self.since_checkpoint = self.since_checkpoint.saturating_add(1);
+        if self.dish == self.checkpoint {
+            return Some(self.since_checkpoint);
+        }
+        if self.since_checkpoint == self.checkpoint_interval {
+            self.checkpoint = self.dish;
+            self.since_checkpoint = 0;
+            self.checkpoint_interval =
+                self.checkpoint_interval.saturating_mul(2);
+        }
+        None
+    }
+
+    /// Moves the game on by a step, to `dish` with `status`, which must be
+    /// what [`PetriDish::step`] gives for the current board.
+    pub(crate) fn record(&mut self, dish: PetriDish, status: Status) -> Step {
+        self.dish = dish;
+        self.steps = self.steps.saturating_add(1);
+        self.ending = match status {
+            Status::Continue if self.dish.is_extinct() => {
+                Some(Ending::Extinction)
+            }
+            Status::Continue => self
+                .find_cycle()
+                .map(|period: u8| Ending::Cycle { period })
+                .or_else(|| {
+                    (self.steps >= PetriDish::MAX_STEPS)
+                        .then_some(Ending::Timeout)
+                }),
+            Status::NormalEnd => Some(Ending::Normal {
+                mana: self.dish.score(self.steps.min(100)),
+            }),
+            Status::AbruptEnd => Some(Ending::Abrupt),
+        };
+
+        Step {
+            number: self.steps,
+            dish: self.dish,
+            status,
+        }
+    }
+
+    /// Runs the rest of the game, returning how it ended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::{
+    ///     Cell, DishError, Ending, PetriDish, Simulation,
+    /// };
+    ///
+    /// // A lone cell dies of loneliness.
+    /// let lonely: PetriDish = PetriDish::new().with(3, 3, Cell::Living)?;
+    /// assert_eq!(Simulation::new(lonely).run(), Ending::Extinction);
+    ///
+    /// // A cell placed next to the Dandelifeon ends the game immediately.
+    /// let hasty: PetriDish = PetriDish::new().with(12, 13, Cell::Living)?;
+    /// assert_eq!(Simulation::new(hasty).run(), Ending::Abrupt);
+    ///
+    /// // A blinker flips back and forth forever.
+    /// let blinker: PetriDish = PetriDish::new()
+    ///     .with(0, 1, Cell::Living)?
+    ///     .with(1, 1, Cell::Living)?
+    ///     .with(2, 1, Cell::Living)?;
+    /// assert_eq!(Simulation::new(blinker).run(), Ending::Cycle { period: 2 });
+    ///
+    /// // An R-pentomino takes a long time to settle down.
+    /// let pentomino: PetriDish = PetriDish::new()
+    ///     .with(3, 1, Cell::Living)?
+    ///     .with(4, 1, Cell::Living)?
+    ///     .with(2, 2, Cell::Living)?
+    ///     .with(3, 2, Cell::Living)?
+    ///     .with(3, 3, Cell::Living)?;
+    /// assert_eq!(Simulation::new(pentomino).run(), Ending::Timeout);
+    /// # Ok::<(), DishError>(())
+    /// ```
+    pub fn run(mut self) -> Ending {
+        loop {
+            if let Some(ending) = self.ending {
+                return ending;
+            }
+            let _: Option<Step> = self.next();
+        }
+    }
+}
+
+impl Iterator for Simulation {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ending.is_some() {
+            return None;
+        }
+        let (dish, status): (PetriDish, Status) = self.dish.step();
+        Some(self.record(dish, status))
+    }
+}
+
+impl FusedIterator for Simulation {}
+
+/// A single step of a [`Simulation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Step {
+    /// The number of this step, starting from 1.
+    number: u8,
+    /// The board after this step.
+    dish: PetriDish,
+    /// The result of this step.
+    status: Status,
+}
+
+impl Step {
+    /// Gets the number of this step. The first step is number 1.
+    pub const fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Gets the board after this step. If the step ended the game abruptly,
+    /// this is the board as it was before the step.
+    pub const fn dish(&self) -> PetriDish {
+        self.dish
+    }
+
+    /// Gets the result of this step.
+    pub const fn status(&self) -> Status {
+        self.status
+    }
+}
+
+/// A summary of how a game played out, for judging a [`PetriDish`] by more
+/// than just its mana.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::simulation::{Ending, GameReport, PetriDish, Status};
+///
+/// let report: GameReport = GameReport::new(&PetriDish::OPTIMAL_100_ROUND);
+///
+/// assert_eq!(report.mana(), 36_000);
+/// assert_eq!(report.ending(), Ending::Normal { mana: 36_000 });
+/// assert_eq!(report.status(), Status::NormalEnd);
+/// assert!(!report.hit_step_cap(), "the game should end on its own");
+/// assert_eq!(report.entered().len(), 6);
+/// assert_eq!(report.initial_cost(), (6, 7));
+/// assert!(report.peak_population() >= report.final_population());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GameReport {
+    /// How the game ended.
+    ending: Ending,
+    /// The [`Status`] of the last step.
+    status: Status,
+    /// The number of steps simulated.
+    steps: u8,
+    /// Which cells of [`PetriDish::LETHAL_ZONE`] were entered, in the same
+    /// order.
+    entered: [bool; 8],
+    /// The most [`Cell::Living`] on the board at once.
+    peak_population: u16,
+    /// The number of [`Cell::Living`] on the last board.
+    final_population: u16,
+    /// The number of [`Cell::Living`] on the initial board.
+    initial_cells: u16,
+    /// The number of [`Cell::Blocked`] on the initial board.
+    initial_blocks: u16,
+}
+
+impl GameReport {
+    /// Plays a game starting from `dish` and reports on how it went. The game
+    /// is played exactly as in [`PetriDish::play`]. To play many games at
+    /// once, use [`PetriDish::play_many`].
+    pub fn new(dish: &PetriDish) -> Self {
+        let mut recorder: Recorder = Recorder::new(*dish);
+        while recorder.advance() {}
+        recorder.finish()
+    }
+
+    /// Gets the mana generated by the game.
+    pub const fn mana(&self) -> u16 {
+        self.ending.mana()
+    }
+
+    /// Gets how the game ended.
+    pub const fn ending(&self) -> Ending {
+        self.ending
+    }
+
+    /// Gets the [`Status`] of the last step of the game.
+    pub const fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Gets the number of steps simulated before the game ended.
+    pub const fn steps(&self) -> u8 {
+        self.steps
+    }
+
+    /// Checks whether the game was cut short after [`PetriDish::MAX_STEPS`]
+    /// steps.
+    pub const fn hit_step_cap(&self) -> bool {
+        matches!(self.ending, Ending::Timeout)
+    }
+
+    /// Gets the coordinates of every cell of [`PetriDish::LETHAL_ZONE`] that a
+    /// [`Cell::Living`] grew into, generating mana.
+    pub fn entered(&self) -> Vec<(u8, u8)> {
+        PetriDish::LETHAL_ZONE
+            .into_iter()
+            .zip(self.entered)
+            .filter_map(|(cell, entered): ((u8, u8), bool)| {
+                entered.then_some(cell)
+            })
+            .collect()
+    }
+
+    /// Gets the most [`Cell::Living`] that were on the board at once,
+    /// including the initial board.
+    pub const fn peak_population(&self) -> u16 {
+        self.peak_population
+    }
+
+    /// Gets the number of [`Cell::Living`] on the last board simulated. If the
+    /// game ended normally, this is counted before they all die.
+    pub const fn final_population(&self) -> u16 {
+        self.final_population
+    }
+
+    /// Gets the number of [`Cell::Living`] and [`Cell::Blocked`] on the
+    /// initial board, as `(living, blocked)`. This is the cost of setting the
+    /// game up.
+    pub const fn initial_cost(&self) -> (u16, u16) {
+        (self.initial_cells, self.initial_blocks)
+    }
+}
+
+/// Plays a game one step at a time, keeping track of everything needed for a
+/// [`GameReport`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Recorder {
+    /// The game being played.
+    simulation: Simulation,
+    /// The [`Status`] of the most recent step.
+    status: Status,
+    /// The most [`Cell::Living`] on the board at once so far.
+    peak_population: u16,
+    /// The number of [`Cell::Living`] and [`Cell::Blocked`] on the initial
+    /// board.
+    initial_cost: (u16, u16),
+}
+
+impl Recorder {
+    /// Starts recording a game starting from `dish`.
+    pub(crate) fn new(dish: PetriDish) -> Self {
+        let initial_cost: (u16, u16) = dish.count_living_and_blocked();
+        Self {
+            simulation: Simulation::new(dish),
+            status: Status::Continue,
+            peak_population: initial_cost.0,
+            initial_cost,
+        }
+    }
+
+    /// Plays the next step of the game. Returns `false` once the game is over.
+    pub(crate) fn advance(&mut self) -> bool {
+        if self.simulation.ending.is_some() {
+            return false;
+        }
+        let (dish, status): (PetriDish, Status) = self.dish().step();
+        self.record(dish, status)
+    }
+
+    /// Records the next step of the game, to `dish` with `status`, which must
+    /// be what [`PetriDish::step`] gives for [`Recorder::dish`]. Returns
+    /// `false` once the game is over.
+    pub(crate) fn record(&mut self, dish: PetriDish, status: Status) -> bool {
+        let step: Step = self.simulation.record(dish, status);
+        self.status = step.status();
+        self.peak_population =
+            self.peak_population.max(step.dish().population());
+        self.simulation.ending.is_none()
+    }
+
+    /// Gets the board as of the most recent step.
+    pub(crate) const fn dish(&self) -> PetriDish {
+        self.simulation.dish
+    }
+
+    /// Stops recording, reporting on the game so far. If the game is not over,
+    /// it is reported as [`Ending::Timeout`].
+    pub(crate) fn finish(self) -> GameReport {
+        let last: PetriDish = self.simulation.dish;
+        let entered: [bool; 8] = PetriDish::LETHAL_ZONE.map(|(x, y)| {
+            self.status == Status::NormalEnd
+                && last.get(x, y) == Ok(Cell::Living)
+        });
+
+        GameReport {
+            ending: self.simulation.ending.unwrap_or(Ending::Timeout),
+            status: self.status,
+            steps: self.simulation.steps,
+            entered,
+            peak_population: self.peak_population,
+            final_population: last.population(),
+            initial_cells: self.initial_cost.0,
+            initial_blocks: self.initial_cost.1,
+        }
+    }
+}
+
+/// How a game came to an end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ending {
+    /// A [`Cell::Living`] grew into the
+    /// uninhabitable zone around the Dandelifeon, generating mana.
+    Normal {
+        /// The mana generated.
+        mana: u16,
+    },
+    /// A [`Cell::Living`] started in the
+    /// uninhabitable zone, so no mana was generated.
+    Abrupt,
+    /// The game went on for [`PetriDish::MAX_STEPS`] steps without ending.
+    Timeout,
+    /// The board returned to a previous state without ending, so it would
+    /// repeat forever.
+    Cycle {
+        /// The number of steps it takes for the board to repeat.
+        period: u8,
+    },
+    /// Every [`Cell::Living`] died without
+    /// reaching the Dandelifeon.
+    Extinction,
+}
+
+impl Ending {
+    /// Gets the mana generated by a game that ended this way.
+    pub const fn mana(self) -> u16 {
+        match self {
+            Self::Normal { mana } => mana,
+            Self::Abrupt
+            | Self::Timeout
+            | Self::Cycle { .. }
+            | Self::Extinction => 0,
+        }
+    }
+}
+
+impl Display for Ending {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Normal { mana } => write!(f, "generated {mana} mana"),
+            Self::Abrupt => write!(f, "started in the uninhabitable zone"),
+            Self::Timeout => {
+                write!(f, "still going after {} steps", PetriDish::MAX_STEPS)
+            }
+            Self::Cycle { period } => {
+                write!(f, "repeats every {period} steps")
+            }
+            Self::Extinction => write!(f, "all living cells died"),
+        }
+    }
+}
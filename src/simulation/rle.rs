@@ -0,0 +1,469 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Reading and writing [`PetriDish`]es as [Golly RLE].
+//!
+//! [Golly RLE]: https://golly.sourceforge.io/Help/formats.html#rle
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::simulation::{Cell, DishError, PetriDish};
+
+/// The maximum length of a line of pattern data when writing RLE, as
+/// recommended by the format.
+const LINE_LENGTH: usize = 70;
+
+/// A wrapper around a [`PetriDish`] which can be written and read as
+/// [Golly RLE].
+///
+/// Coordinates in RLE files follow Golly's conventions: x increases to the
+/// right and y increases downwards. The [`Cell::Dandelifeon`] is always at
+/// Golly's origin, (0, 0), so a [`PetriDish`] covers -12 to 12 on both axes.
+/// The position of a pattern is given by the `#CXRLE Pos=x,y` line, which
+/// holds the coordinates of the top left corner of the pattern's bounding box.
+///
+/// Boards without any [`Cell::Blocked`] are written as plain two-state
+/// `B3/S23` patterns, which Golly can open as-is. Since Golly has no notion of
+/// a blocker, boards with any [`Cell::Blocked`] are written using the
+/// multi-state letters instead, with `.` for [`Cell::Dead`], `A` for
+/// [`Cell::Living`] and `B` for [`Cell::Blocked`], and with the rule set to
+/// `Dandelifeon`. Both sets of letters are accepted when reading, and the
+/// rule is ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::simulation::{PetriDish, Rle, RleError};
+///
+/// let record: PetriDish = PetriDish::OPTIMAL_100_ROUND;
+/// let rle: String = record.rle().to_string();
+///
+/// assert!(rle.starts_with("#CXRLE Pos=-5,-11\nx = 11, y = 10"));
+/// assert_eq!(rle.parse::<Rle>()?.dish(), record);
+///
+/// // Without a position, the pattern is centred on the Dandelifeon.
+/// let glider: &str = "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!";
+/// let dish: PetriDish = glider.parse::<Rle>()?.dish();
+/// assert_eq!(dish.count_living_and_blocked(), (5, 0));
+///
+/// // Patterns which do not fit on the board are rejected.
+/// assert_eq!(
+///     Rle::parse_at(glider, (11, 0)),
+///     Err(RleError::OutsideWindow { x: 13, y: 1 })
+/// );
+/// assert_eq!(
+///     "x = 1, y = 1\n4294967295b!".parse::<Rle>(),
+///     Err(RleError::RunTooLong { line: 2 })
+/// );
+/// # Ok::<(), dandelifeon::simulation::RleError>(())
+/// ```
+///
+/// [Golly RLE]: https://golly.sourceforge.io/Help/formats.html#rle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rle(PetriDish);
+
+impl Rle {
+    /// Wraps the given [`PetriDish`].
+    pub(crate) const fn new(dish: PetriDish) -> Self {
+        Self(dish)
+    }
+
+    /// Get the wrapped [`PetriDish`].
+    pub const fn dish(self) -> PetriDish {
+        self.0
+    }
+
+    /// Parses a [`PetriDish`] from RLE, placing the top left corner of the
+    /// pattern at the given Golly coordinates relative to the
+    /// [`Cell::Dandelifeon`]. Any `#CXRLE Pos=x,y` line is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not valid RLE, if it uses states other
+    /// than those described in the [`Rle`] documentation, or if any
+    /// [`Cell::Living`] or [`Cell::Blocked`] would be placed outside of the
+    /// board or on top of the [`Cell::Dandelifeon`], or if a run of
+    /// [`Cell::Dead`] would go past the right edge of the board.
+    pub fn parse_at(
+        text: &str,
+        offset: (i32, i32),
+    ) -> Result<PetriDish, RleError> {
+        parse(text, Some(offset))
+    }
+}
+
+impl From<Rle> for PetriDish {
+    fn from(value: Rle) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for Rle {
+    type Err = RleError;
+
+    /// Parses a [`PetriDish`] from RLE. If a `#CXRLE Pos=x,y` line is present,
+    /// the pattern is placed accordingly. Otherwise, the pattern is centred on
+    /// the [`Cell::Dandelifeon`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, None).map(Self)
+    }
+}
+
+impl Display for Rle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut bounds: Option<(u8, u8, u8, u8)> = None;
+        let mut any_blocked: bool = false;
+        for (x, y, cell) in self.0.iter_cells() {
+            if matches!(cell, Cell::Living | Cell::Blocked) {
+                any_blocked |= cell == Cell::Blocked;
+                bounds = Some(match bounds {
+                    Some((left, right, bottom, top)) => {
+                        (left.min(x), right.max(x), bottom.min(y), top.max(y))
+                    }
+                    None => (x, x, y, y),
+                });
+            }
+        }
+
+        let Some((left, right, bottom, top)) = bounds else {
+            writeln!(f, "#CXRLE Pos=0,0")?;
+            writeln!(f, "x = 0, y = 0, rule = B3/S23")?;
+            return writeln!(f, "!");
+        };
+
+        let (dead, living, blocked, rule): (char, char, char, &str) =
+            if any_blocked {
+                ('.', 'A', 'B', "Dandelifeon")
+            } else {
+                ('b', 'o', 'b', "B3/S23")
+            };
+
+        #[expect(
+            clippy::arithmetic_side_effects,
+            reason = "left <= right and bottom <= top are all in the range
+            0..25, none of this can overflow"
+        )]
+        let (position_x, position_y, width, height): (i8, i8, u8, u8) = (
+            left.cast_signed() - 12,
+            12 - top.cast_signed(),
+            right - left + 1,
+            top - bottom + 1,
+        );
+        writeln!(f, "#CXRLE Pos={position_x},{position_y}")?;
+        writeln!(f, "x = {width}, y = {height}, rule = {rule}")?;
+
+        let mut writer: RunWriter<'_, '_> = RunWriter {
+            formatter: f,
+            column: 0,
+            pending: None,
+        };
+        let mut empty_rows: u32 = 0;
+        for y in (bottom..=top).rev() {
+            let mut row: Vec<char> = (left..=right)
+                .map(|x: u8| match Cell::from_bits(self.0.read(x, y)) {
+                    Cell::Living => living,
+                    Cell::Blocked => blocked,
+                    Cell::Dead | Cell::Dandelifeon => dead,
+                })
+                .collect();
+            while row.last() == Some(&dead) {
+                let _: Option<char> = row.pop();
+            }
+            if row.is_empty() {
+                empty_rows = empty_rows.saturating_add(1);
+                continue;
+            }
+            if y != top {
+                writer.push('$', empty_rows.saturating_add(1))?;
+            }
+            empty_rows = 0;
+            for tag in row {
+                writer.push(tag, 1)?;
+            }
+        }
+        writer.flush()?;
+        writeln!(writer.formatter, "!")
+    }
+}
+
+/// Collects runs of identical tags and writes them out, wrapping lines at
+/// [`LINE_LENGTH`].
+struct RunWriter<'writer, 'formatter> {
+    /// Where to write to.
+    formatter: &'writer mut Formatter<'formatter>,
+    /// The length of the current line.
+    column: usize,
+    /// The tag and length of the run which has not been written yet.
+    pending: Option<(char, u32)>,
+}
+
+impl RunWriter<'_, '_> {
+    /// Adds `count` copies of `tag`, writing out the previous run if it was a
+    /// different tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the [`Formatter`] fails.
+    fn push(&mut self, tag: char, count: u32) -> fmt::Result {
+        match self.pending {
+            Some((pending, length)) if pending == tag => {
+                self.pending = Some((tag, length.saturating_add(count)));
+            }
+            Some(_) => {
+                self.flush()?;
+                self.pending = Some((tag, count));
+            }
+            None => self.pending = Some((tag, count)),
+        }
+        Ok(())
+    }
+
+    /// Writes out the pending run, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the [`Formatter`] fails.
+    fn flush(&mut self) -> fmt::Result {
+        let Some((tag, count)) = self.pending.take() else {
+            return Ok(());
+        };
+        let run: String = if count == 1 {
+            tag.to_string()
+        } else {
+            format!("{count}{tag}")
+        };
+        if self.column.saturating_add(run.len()) > LINE_LENGTH {
+            writeln!(self.formatter)?;
+            self.column = 0;
+        }
+        self.column = self.column.saturating_add(run.len());
+        self.formatter.write_str(&run)
+    }
+}
+
+/// An error encountered while reading RLE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RleError {
+    /// No `x = ..., y = ...` header line was found.
+    MissingHeader,
+    /// A header or `#CXRLE` line could not be understood.
+    InvalidHeader {
+        /// The line the error was found on, counting from 1.
+        line: usize,
+    },
+    /// A character in the pattern data was not understood.
+    UnexpectedChar {
+        /// The line the error was found on, counting from 1.
+        line: usize,
+        /// The column the error was found at, counting from 1.
+        column: usize,
+        /// The character that was found.
+        found: char,
+    },
+    /// A run count was too large, or a run of [`Cell::Dead`] went past the
+    /// right edge of the board.
+    RunTooLong {
+        /// The line the error was found on, counting from 1.
+        line: usize,
+    },
+    /// A [`Cell::Living`] or [`Cell::Blocked`] lies outside of the 25 by 25
+    /// board.
+    OutsideWindow {
+        /// The Golly x coordinate of the offending cell.
+        x: i64,
+        /// The Golly y coordinate of the offending cell.
+        y: i64,
+    },
+    /// A [`Cell::Living`] or [`Cell::Blocked`] lies on top of the
+    /// [`Cell::Dandelifeon`].
+    OverwritesDandelifeon,
+}
+
+impl Display for RleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::MissingHeader => write!(f, "no \"x = ..., y = ...\" header"),
+            Self::InvalidHeader { line } => {
+                write!(f, "line {line}: invalid header")
+            }
+            Self::UnexpectedChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {line}, column {column}: unexpected character '{found}'"
+            ),
+            Self::RunTooLong { line } => {
+                write!(f, "line {line}: run count is too large")
+            }
+            Self::OutsideWindow { x, y } => write!(
+                f,
+                "cell at ({x}, {y}) is outside of the board, which spans -12 \
+                to 12 on both axes"
+            ),
+            Self::OverwritesDandelifeon => {
+                write!(f, "a cell lies on top of the Dandelifeon at (0, 0)")
+            }
+        }
+    }
+}
+
+impl Error for RleError {}
+
+/// Parses the value of a `key=value` or `key = value` pair.
+fn header_value<'text>(field: &'text str, key: &str) -> Option<&'text str> {
+    let (name, value) = field.split_once('=')?;
+    (name.trim() == key).then_some(value.trim())
+}
+
+/// Parses RLE into a [`PetriDish`], placing the top left corner of the pattern
+/// at `offset` if given, or according to the text otherwise.
+///
+/// # Errors
+///
+/// See [`Rle::parse_at`].
+fn parse(
+    text: &str,
+    offset: Option<(i32, i32)>,
+) -> Result<PetriDish, RleError> {
+    let mut lines = text.lines().enumerate();
+    let mut position: Option<(i64, i64)> = None;
+
+    let (width, height): (i64, i64) = loop {
+        let Some((index, line)) = lines.next() else {
+            return Err(RleError::MissingHeader);
+        };
+        let line_number: usize = index.saturating_add(1);
+        let invalid: RleError = RleError::InvalidHeader { line: line_number };
+        let line: &str = line.trim();
+        if let Some(cxrle) = line.strip_prefix("#CXRLE") {
+            for field in cxrle.split_whitespace() {
+                if let Some(value) = header_value(field, "Pos") {
+                    let (x, y) = value.split_once(',').ok_or(invalid)?;
+                    position = Some((
+                        x.trim().parse().ok().ok_or(invalid)?,
+                        y.trim().parse().ok().ok_or(invalid)?,
+                    ));
+                }
+            }
+        } else if line.is_empty() || line.starts_with('#') {
+            // Comments and other metadata.
+        } else {
+            let mut fields = line.split(',');
+            let width: i64 = fields
+                .next()
+                .and_then(|field: &str| header_value(field, "x"))
+                .and_then(|value: &str| value.parse().ok())
+                .ok_or(invalid)?;
+            let height: i64 = fields
+                .next()
+                .and_then(|field: &str| header_value(field, "y"))
+                .and_then(|value: &str| value.parse().ok())
+                .ok_or(invalid)?;
+            break (width, height);
+        }
+    };
+
+    let (left, top): (i64, i64) = match (offset, position) {
+        (Some((x, y)), _) => (i64::from(x), i64::from(y)),
+        (None, Some(position)) => position,
+        (None, None) => (
+            width.div_euclid(2).saturating_neg(),
+            height.div_euclid(2).saturating_neg(),
+        ),
+    };
+
+    let mut dish: PetriDish = PetriDish::new();
+    let (mut x, mut y): (i64, i64) = (left, top);
+    'lines: for (index, line) in lines {
+        let line_number: usize = index.saturating_add(1);
+        let mut run: Option<u32> = None;
+        for (column, character) in line.chars().enumerate() {
+            let count: u32 = run.unwrap_or(1);
+            let cell: Option<Cell> = match character {
+                '0'..='9' => {
+                    let digit: u32 = character.to_digit(10).unwrap_or(0);
+                    run = Some(
+                        run.unwrap_or(0)
+                            .checked_mul(10)
+                            .and_then(|run: u32| run.checked_add(digit))
+                            .ok_or(RleError::RunTooLong {
+                                line: line_number,
+                            })?,
+                    );
+                    continue;
+                }
+                'b' | '.' => Some(Cell::Dead),
+                'o' | 'A' => Some(Cell::Living),
+                'B' => Some(Cell::Blocked),
+                '$' => {
+                    x = left;
+                    y = y.saturating_add(i64::from(count));
+                    None
+                }
+                '!' => break 'lines,
+                _ if character.is_whitespace() => continue,
+                _ => {
+                    return Err(RleError::UnexpectedChar {
+                        line: line_number,
+                        column: column.saturating_add(1),
+                        found: character,
+                    });
+                }
+            };
+            run = None;
+            let Some(cell) = cell else {
+                continue;
+            };
+            let end: i64 = x.saturating_add(i64::from(count));
+            if cell == Cell::Dead {
+                if end > 13 {
+                    return Err(RleError::RunTooLong { line: line_number });
+                }
+                x = end;
+                continue;
+            }
+            // Stops at the first cell outside of the board, so this is short.
+            while x < end {
+                place(&mut dish, x, y, cell)?;
+                x = x.saturating_add(1);
+            }
+        }
+    }
+
+    Ok(dish)
+}
+
+/// Places `cell` at the given Golly coordinates.
+///
+/// # Errors
+///
+/// Returns an error if the coordinates are outside of the board or on top of
+/// the [`Cell::Dandelifeon`].
+fn place(
+    dish: &mut PetriDish,
+    x: i64,
+    y: i64,
+    cell: Cell,
+) -> Result<(), RleError> {
+    let outside: RleError = RleError::OutsideWindow { x, y };
+    let board_x: u8 = x
+        .checked_add(12)
+        .and_then(|x: i64| u8::try_from(x).ok())
+        .ok_or(outside)?;
+    let board_y: u8 = 12_i64
+        .checked_sub(y)
+        .and_then(|y: i64| u8::try_from(y).ok())
+        .ok_or(outside)?;
+    match dish.set(board_x, board_y, cell) {
+        Ok(()) => Ok(()),
+        Err(DishError::OverwritesDandelifeon) => {
+            Err(RleError::OverwritesDandelifeon)
+        }
+        Err(_) => Err(outside),
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Word-parallel stepping of a [`PetriDish`], which updates a whole row of
+//! [`Cell`](crate::simulation::Cell)s at once.
+//!
+//! Each row is split into bitplanes with one bit per cell, kept at the low bit
+//! of the cell's 2 bit slot so that they line up with the packed row. The 8
+//! neighbor planes of a row are then summed with bitwise full adders, giving
+//! the count of living neighbors of every cell as a 3 bit number spread over
+//! 3 planes. A count of 8 wraps around to 0, which is harmless, since only
+//! counts of 2 and 3 matter.
+
+use crate::simulation::{LOW_BITS, PetriDish, Status};
+
+/// The high bit of every cell in a row.
+const HIGH_BITS: u64 = LOW_BITS << 1;
+
+/// The low bits of the cells in columns 11 to 13, where the uninhabitable
+/// zone is.
+const ZONE_COLUMNS: u64 = 0b01_0101 << 22;
+
+/// Gets the plane of the [`Cell::Living`](crate::simulation::Cell::Living) in
+/// a row.
+const fn living(row: u64) -> u64 {
+    row & !(row >> 1) & LOW_BITS
+}
+
+/// Adds three planes together, returning the planes of the sum and the carry.
+const fn full_add(first: u64, second: u64, third: u64) -> (u64, u64) {
+    let partial: u64 = first ^ second;
+    (partial ^ third, (first & second) | (third & partial))
+}
+
+/// Adds the cells to the left and right of every cell in a plane, returning
+/// the planes of the sum and the carry.
+const fn add_sides(plane: u64) -> (u64, u64) {
+    let (left, right): (u64, u64) = ((plane << 2) & LOW_BITS, plane >> 2);
+    (left ^ right, left & right)
+}
+
+/// Adds a cell and the cells to the left and right of it for every cell in a
+/// plane, returning the planes of the sum and the carry.
+const fn add_row(plane: u64) -> (u64, u64) {
+    full_add((plane << 2) & LOW_BITS, plane, plane >> 2)
+}
+
+/// Simulates a single step of the game for the entire [`PetriDish`].
+pub(crate) fn step(dish: &PetriDish) -> (PetriDish, Status) {
+    let rows: [u64; 25] = dish.0;
+    let mut planes: [u64; 27] = [0; 27];
+    for (plane, row) in planes.iter_mut().skip(1).zip(rows) {
+        *plane = living(row);
+    }
+
+    let zone: u64 = planes
+        .iter()
+        .skip(12)
+        .take(3)
+        .fold(0, |zone: u64, plane: &u64| zone | plane);
+    if zone & ZONE_COLUMNS != 0 {
+        return (*dish, Status::AbruptEnd);
+    }
+
+    let mut next: [u64; 25] = [0; 25];
+    let mut zone: u64 = 0;
+    for (y, ((next, row), window)) in
+        next.iter_mut().zip(rows).zip(planes.windows(3)).enumerate()
+    {
+        let &[below, middle, above] = window else {
+            continue;
+        };
+        let (above_sum, above_carry): (u64, u64) = add_row(above);
+        let (below_sum, below_carry): (u64, u64) = add_row(below);
+        let (sides_sum, sides_carry): (u64, u64) = add_sides(middle);
+
+        let (ones, ones_carry): (u64, u64) =
+            full_add(above_sum, below_sum, sides_sum);
+        let (partial, fours_first): (u64, u64) =
+            full_add(above_carry, below_carry, sides_carry);
+        let (twos, fours_second): (u64, u64) =
+            (partial ^ ones_carry, partial & ones_carry);
+        let fours: u64 = fours_first ^ fours_second;
+
+        let dead: u64 = !(row | (row >> 1)) & LOW_BITS;
+        let living: u64 = twos & !fours & (middle | (dead & ones));
+        let high: u64 = row & HIGH_BITS;
+        *next = living | high | ((high >> 1) & row);
+
+        if matches!(y, 11..14) {
+            zone |= living;
+        }
+    }
+
+    let status: Status = if zone & ZONE_COLUMNS == 0 {
+        Status::Continue
+    } else {
+        Status::NormalEnd
+    };
+    (PetriDish(next), status)
+}
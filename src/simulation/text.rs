@@ -0,0 +1,361 @@
+// SPDX-FileCopyrightText: Copyright © 2025 hashcatHitman
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Reading [`PetriDish`]es back from text.
+//!
+//! Two formats are understood:
+//!
+//! - The "full" format, which is exactly what the [`Display`] implementation of
+//!   [`PetriDish`] emits. Every [`Cell`] takes up 5 columns, with the lethal
+//!   zone wrapped in `{}` and the [`Cell::Dandelifeon`] shown as `<<*>>`.
+//! - The "compact" format, which uses exactly one character per [`Cell`], as
+//!   emitted by [`Compact`].
+//!
+//! In both formats, rows are written from `y = 24` at the top down to `y = 0`
+//! at the bottom, [`Cell::Living`] is `O`, [`Cell::Dead`] is `.`,
+//! [`Cell::Blocked`] is `X` and the [`Cell::Dandelifeon`] is `*`. Blank lines
+//! and trailing whitespace are ignored, so output copied from a terminal can be
+//! pasted straight back in.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::iter::{Enumerate, Peekable};
+use core::str::{Chars, FromStr};
+
+use crate::simulation::{Cell, PetriDish};
+
+/// A wrapper around a [`PetriDish`] whose [`Display`] implementation writes
+/// the compact, one character per [`Cell`] text format.
+///
+/// # Example
+///
+/// ```rust
+/// use dandelifeon::simulation::PetriDish;
+///
+/// let record: PetriDish = PetriDish::OPTIMAL_100_ROUND;
+/// let compact: String = record.compact().to_string();
+///
+/// assert_eq!(compact.lines().count(), 25);
+/// assert_eq!(compact.parse::<PetriDish>(), Ok(record));
+/// assert_eq!(record.to_string().parse::<PetriDish>(), Ok(record));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Compact(PetriDish);
+
+impl Compact {
+    /// Wraps the given [`PetriDish`].
+    pub(crate) const fn new(dish: PetriDish) -> Self {
+        Self(dish)
+    }
+}
+
+impl Display for Compact {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for y in (0..25).rev() {
+            for x in 0..25 {
+                write!(f, "{}", Cell::from_bits(self.0.read(x, y)).symbol())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error encountered while parsing a [`PetriDish`] from text.
+///
+/// Lines and columns are counted from 1, and columns count [`char`]s rather
+/// than bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParseDishError {
+    /// The line the error was found on.
+    line: usize,
+    /// The column the error was found at.
+    column: usize,
+    /// What went wrong.
+    kind: ParseDishErrorKind,
+}
+
+impl ParseDishError {
+    /// Get the line the error was found on.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Get the column the error was found at.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Get what went wrong.
+    pub const fn kind(&self) -> ParseDishErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ParseDishError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for ParseDishError {}
+
+/// The ways in which parsing a [`PetriDish`] from text can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParseDishErrorKind {
+    /// A character other than the one expected was found.
+    UnexpectedChar {
+        /// The character that was found.
+        found: char,
+        /// A description of what was expected instead.
+        expected: &'static str,
+    },
+    /// A row ended before all 25 [`Cell`]s were read.
+    UnexpectedEndOfLine {
+        /// A description of what was expected instead.
+        expected: &'static str,
+    },
+    /// Fewer than 25 rows were given.
+    TooFewRows,
+    /// More than 25 rows were given.
+    TooManyRows,
+}
+
+impl Display for ParseDishErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::UnexpectedChar { found, expected } => {
+                write!(f, "found '{found}', expected {expected}")
+            }
+            Self::UnexpectedEndOfLine { expected } => {
+                write!(f, "line ended early, expected {expected}")
+            }
+            Self::TooFewRows => write!(f, "expected 25 rows, found fewer"),
+            Self::TooManyRows => write!(f, "expected 25 rows, found more"),
+        }
+    }
+}
+
+/// A description of the [`Cell`]s allowed anywhere except at (12, 12).
+const EXPECTED_CELL: &str = "'O', '.' or 'X'";
+
+/// A description of the [`Cell`] allowed at (12, 12).
+const EXPECTED_DANDELIFEON: &str = "'*'";
+
+/// A cursor over a single line of text, which keeps track of the column for
+/// error reporting.
+struct Cursor<'line> {
+    /// The line number being read.
+    line: usize,
+    /// The number of characters in the line.
+    length: usize,
+    /// The characters of the line, numbered from 0.
+    chars: Peekable<Enumerate<Chars<'line>>>,
+}
+
+impl Cursor<'_> {
+    /// Builds a [`ParseDishError`] at the current position.
+    fn error(&mut self, kind: ParseDishErrorKind) -> ParseDishError {
+        let column: usize = self
+            .chars
+            .peek()
+            .map_or(self.length, |&(index, _): &(usize, char)| index);
+        ParseDishError {
+            line: self.line,
+            column: column.saturating_add(1),
+            kind,
+        }
+    }
+
+    /// Consumes exactly the given literal text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line does not continue with `literal`.
+    fn literal(
+        &mut self,
+        literal: &str,
+        expected: &'static str,
+    ) -> Result<(), ParseDishError> {
+        for wanted in literal.chars() {
+            match self.chars.peek() {
+                Some(&(_, found)) if found == wanted => {
+                    let _: Option<(usize, char)> = self.chars.next();
+                }
+                Some(&(_, found)) => {
+                    return Err(self.error(
+                        ParseDishErrorKind::UnexpectedChar { found, expected },
+                    ));
+                }
+                None => {
+                    return Err(self.error(
+                        ParseDishErrorKind::UnexpectedEndOfLine { expected },
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Consumes a single [`Cell`] symbol. The [`Cell::Dandelifeon`] is only
+    /// accepted if `centre` is true, and is the only [`Cell`] accepted if it
+    /// is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next character is not an acceptable [`Cell`].
+    fn cell(&mut self, centre: bool) -> Result<Cell, ParseDishError> {
+        let expected: &'static str = if centre {
+            EXPECTED_DANDELIFEON
+        } else {
+            EXPECTED_CELL
+        };
+        let cell: Cell = match (self.chars.peek(), centre) {
+            (Some(&(_, '*')), true) => Cell::Dandelifeon,
+            (Some(&(_, 'O')), false) => Cell::Living,
+            (Some(&(_, '.')), false) => Cell::Dead,
+            (Some(&(_, 'X')), false) => Cell::Blocked,
+            (Some(&(_, found)), _) => {
+                return Err(self.error(ParseDishErrorKind::UnexpectedChar {
+                    found,
+                    expected,
+                }));
+            }
+            (None, _) => {
+                return Err(self.error(
+                    ParseDishErrorKind::UnexpectedEndOfLine { expected },
+                ));
+            }
+        };
+        let _: Option<(usize, char)> = self.chars.next();
+        Ok(cell)
+    }
+
+    /// Ensures nothing but whitespace remains on the line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error at the first character that is not whitespace.
+    fn end(&mut self) -> Result<(), ParseDishError> {
+        while let Some(&(_, found)) = self.chars.peek() {
+            if !found.is_whitespace() {
+                return Err(self.error(ParseDishErrorKind::UnexpectedChar {
+                    found,
+                    expected: "the end of the line",
+                }));
+            }
+            let _: Option<(usize, char)> = self.chars.next();
+        }
+        Ok(())
+    }
+}
+
+/// Parses a single row of either text format into `dish`.
+///
+/// # Errors
+///
+/// Returns an error at the first character that does not fit the format.
+fn parse_row(
+    dish: &mut PetriDish,
+    line: usize,
+    text: &str,
+    y: u8,
+    compact: bool,
+) -> Result<(), ParseDishError> {
+    let text: &str = text.trim_end();
+    let mut cursor: Cursor<'_> = Cursor {
+        line,
+        length: text.chars().count(),
+        chars: text.chars().enumerate().peekable(),
+    };
+    for x in 0..25 {
+        let centre: bool = x == 12 && y == 12;
+        let (prefix, suffix, expected): (&str, &str, &'static str) =
+            match (compact, x, y) {
+                (true, ..) => ("", "", ""),
+                (false, 12, 12) => ("<<", ">>", "\"<<*>>\""),
+                (false, 11..14, 11 | 13) | (false, 11 | 13, 11..14) => {
+                    (" {", "} ", "a lethal zone cell like \" {.} \"")
+                }
+                (false, ..) => ("  ", "  ", "a cell like \"  .  \""),
+            };
+        cursor.literal(prefix, expected)?;
+        let cell: Cell = cursor.cell(centre)?;
+        if !centre {
+            dish.write(x, y, cell as u8);
+        }
+        // Trailing whitespace may have been trimmed from the final suffix.
+        if x == 24 {
+            cursor.literal(suffix.trim_end(), expected)?;
+        } else {
+            cursor.literal(suffix, expected)?;
+        }
+    }
+    cursor.end()
+}
+
+impl FromStr for PetriDish {
+    type Err = ParseDishError;
+
+    /// Parses a [`PetriDish`] from either the full format emitted by its
+    /// [`Display`] implementation or the compact format emitted by
+    /// [`Compact`]. The format is detected from the first non-blank line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dandelifeon::simulation::{
+    ///     ParseDishError, ParseDishErrorKind, PetriDish,
+    /// };
+    ///
+    /// let mut text: String = PetriDish::new().compact().to_string();
+    /// text.replace_range(3..4, "?");
+    ///
+    /// let error: ParseDishError = text.parse::<PetriDish>().unwrap_err();
+    /// assert_eq!((error.line(), error.column()), (1, 4));
+    /// assert_eq!(
+    ///     error.kind(),
+    ///     ParseDishErrorKind::UnexpectedChar {
+    ///         found: '?',
+    ///         expected: "'O', '.' or 'X'"
+    ///     }
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut dish: Self = Self::new();
+        let mut rows = s
+            .lines()
+            .enumerate()
+            .filter(|&(_, line): &(usize, &str)| !line.trim().is_empty());
+        let mut compact: Option<bool> = None;
+        let mut last_line: usize = 0;
+
+        for y in (0..25).rev() {
+            let Some((index, line)) = rows.next() else {
+                return Err(ParseDishError {
+                    line: last_line.saturating_add(1),
+                    column: 1,
+                    kind: ParseDishErrorKind::TooFewRows,
+                });
+            };
+            last_line = index.saturating_add(1);
+            let compact: bool =
+                *compact.get_or_insert_with(|| !line.starts_with(' '));
+            parse_row(&mut dish, last_line, line, y, compact)?;
+        }
+
+        match rows.next() {
+            Some((index, _)) => Err(ParseDishError {
+                line: index.saturating_add(1),
+                column: 1,
+                kind: ParseDishErrorKind::TooManyRows,
+            }),
+            None => Ok(dish),
+        }
+    }
+}